@@ -0,0 +1,33 @@
+use common::{ParseError, Solution};
+use std::{convert::Infallible, fmt::Display};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    /// Calories carried by each elf, from the least to the most
+    type Input = Vec<u32>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let mut x = input
+            .split("\n\n")
+            .map(|v| {
+                v.split('\n')
+                    .filter_map(|v| v.parse::<u32>().ok())
+                    .sum::<u32>()
+            })
+            .collect::<Vec<u32>>();
+
+        x.sort();
+        Ok(x)
+    }
+
+    fn part_one(x: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(x[x.len() - 1])
+    }
+
+    fn part_two(x: &Self::Input) -> Result<impl Display, Self::Error> {
+        let sum_biggest_tree = x[x.len() - 3..].iter().sum::<u32>();
+        Ok(sum_biggest_tree)
+    }
+}
@@ -1,23 +1,11 @@
-use std::fs;
+use common::Solution;
+use std::{error::Error, fs};
+use y2022_day_01::Day01;
 
-fn main() {
-    let input = fs::read_to_string("input.txt")
-        .unwrap();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = fs::read_to_string("input.txt")?;
+    let x = Day01::parse(&input)?;
 
-    let mut x = input.split("\n\n")
-        .map(|v| {
-            v.split("\n")
-                .filter_map(|v| v.parse::<u32>().ok())
-                .sum::<u32>()
-        })
-        .collect::<Vec<u32>>();
-
-    x.sort();
-
-    let sum_biggest_tree = &x[x.len()-3..]
-        .to_vec()
-        .iter()
-        .sum::<u32>();
-
-    println!("Items: {:?};\nfirst_tree: {:?}", x, sum_biggest_tree);
+    println!("Items: {:?};\nfirst_tree: {}", x, Day01::part_two(&x)?);
+    Ok(())
 }
@@ -0,0 +1,157 @@
+use common::{ParseError, Solution};
+use std::{convert::Infallible, fmt::Display};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    /// Rounds of the strategy guide read as the hand to play, for part one,
+    /// and as the outcome to reach, for part two
+    type Input = (Vec<Game>, Vec<Game2>);
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let games = input
+            .split_terminator('\n')
+            .filter_map(Game::from_string)
+            .collect();
+        let games2 = input
+            .split_terminator('\n')
+            .filter_map(|line| Game2::from_string(line).ok())
+            .collect();
+        Ok((games, games2))
+    }
+
+    fn part_one((games, _): &Self::Input) -> Result<impl Display, Self::Error> {
+        let game_points = games
+            .iter()
+            .map(|game| {
+                let outcome = game.play();
+                outcome as u32 + game.you as u32
+            })
+            .sum::<u32>();
+        Ok(game_points)
+    }
+
+    fn part_two((_, games): &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(games.iter().map(|game| game.get_points()).sum::<u32>())
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy)]
+pub enum Hand {
+    Rock = 1,
+    Paper = 2,
+    Scissors = 3,
+}
+
+impl Hand {
+    fn beats(&self) -> Hand {
+        match *self {
+            Hand::Rock => Hand::Scissors,
+            Hand::Paper => Hand::Rock,
+            Hand::Scissors => Hand::Paper,
+        }
+    }
+
+    fn beaten(&self) -> Hand {
+        match *self {
+            Hand::Scissors => Hand::Rock,
+            Hand::Rock => Hand::Paper,
+            Hand::Paper => Hand::Scissors,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Outcome {
+    Victory = 6,
+    Failure = 0,
+    Draw = 3,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    opponent: Hand,
+    you: Hand,
+}
+
+impl Game {
+    fn from_string(strategy: &str) -> Option<Game> {
+        let hands = strategy.split_whitespace().collect::<Vec<&str>>();
+
+        if hands.len() != 2 {
+            return None;
+        }
+
+        let opponent = match hands[0] {
+            "A" => Some(Hand::Rock),
+            "B" => Some(Hand::Paper),
+            "C" => Some(Hand::Scissors),
+            _ => None,
+        };
+
+        let you = match hands[1] {
+            "X" => Some(Hand::Rock),
+            "Y" => Some(Hand::Paper),
+            "Z" => Some(Hand::Scissors),
+            _ => None,
+        };
+
+        match (you, opponent) {
+            (Some(you), Some(opponent)) => Some(Game { you, opponent }),
+            _ => None,
+        }
+    }
+
+    fn play(&self) -> Outcome {
+        if self.you.beats() == self.opponent {
+            Outcome::Victory
+        } else if self.opponent.beats() == self.you {
+            Outcome::Failure
+        } else {
+            Outcome::Draw
+        }
+    }
+}
+
+pub struct Game2 {
+    opponent: Hand,
+    expected_outcome: Outcome,
+}
+
+impl Game2 {
+    fn from_string(line: &str) -> Result<Game2, &'static str> {
+        let round = line.split_whitespace().collect::<Vec<&str>>();
+
+        let opponent = round[0];
+        let expected_outcome = round[1];
+
+        let opponent = match opponent {
+            "A" => Hand::Rock,
+            "B" => Hand::Paper,
+            "C" => Hand::Scissors,
+            _ => return Err("Invalid opponent input"),
+        };
+
+        let expected_outcome = match expected_outcome {
+            "X" => Outcome::Failure,
+            "Y" => Outcome::Draw,
+            "Z" => Outcome::Victory,
+            _ => return Err("Invalid expected_outcome"),
+        };
+
+        Ok(Game2 {
+            opponent,
+            expected_outcome,
+        })
+    }
+
+    fn get_points(&self) -> u32 {
+        let my_hand = match self.expected_outcome {
+            Outcome::Draw => self.opponent,
+            Outcome::Failure => self.opponent.beats(),
+            Outcome::Victory => self.opponent.beaten(),
+        };
+        my_hand as u32 + self.expected_outcome as u32
+    }
+}
@@ -0,0 +1,71 @@
+use common::{ParseError, Solution};
+use std::{collections::HashMap, convert::Infallible, fmt::Display};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    /// Lines of the rucksack list
+    type Input = Vec<String>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.split_terminator('\n').map(String::from).collect())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        let prority_map = init_priority();
+
+        let priority_sum = input
+            .iter()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .map(|char_vec| {
+                let middle = char_vec.len() / 2;
+                let (first, second) = (&char_vec[..middle], &char_vec[middle..]);
+
+                let duplicated = second
+                    .iter()
+                    .find(|&item| first.contains(item))
+                    .expect("Expected first half to contain an item from the second half");
+                *duplicated
+            })
+            .filter_map(|duplicated| prority_map.get(&duplicated))
+            .sum::<usize>();
+        Ok(priority_sum)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        let prority_map = init_priority();
+
+        let badge_priority_sum = input
+            .chunks(3)
+            .filter_map(|group| {
+                let first = &group[0];
+                let treplicated = first
+                    .chars()
+                    .find(|item| {
+                        let second = &group[1];
+                        let third = &group[2];
+                        second.contains(*item) && third.contains(*item)
+                    })
+                    .expect("Expected a char to be on all 3 lines");
+                prority_map.get(&treplicated)
+            })
+            .sum::<usize>();
+        Ok(badge_priority_sum)
+    }
+}
+
+fn init_priority() -> HashMap<char, usize> {
+    let mut priority = HashMap::new();
+
+    ('a'..='z').enumerate().for_each(|(i, v)| {
+        priority.insert(v, i + 1);
+    });
+
+    ('A'..='Z').enumerate().for_each(|(i, v)| {
+        priority.insert(v, i + 27);
+    });
+
+    assert_eq!(priority.len(), 26 * 2);
+    priority
+}
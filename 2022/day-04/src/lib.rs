@@ -0,0 +1,69 @@
+use common::{ParseError, Solution};
+use std::{convert::Infallible, fmt::Display, ops};
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    /// Pairs of sections assigned to each pair of elves
+    type Input = Vec<(Section, Section)>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let sections = input
+            .split_terminator('\n')
+            .map(|line| {
+                let mut sections = line.split(',').map(Section::from_string);
+                (sections.next().unwrap(), sections.next().unwrap())
+            })
+            .collect();
+        Ok(sections)
+    }
+
+    fn part_one(sections: &Self::Input) -> Result<impl Display, Self::Error> {
+        let intersect = sections
+            .iter()
+            .map(|(s1, s2)| s1.intersect(s2))
+            .map(|x| x as u32)
+            .sum::<u32>();
+        Ok(intersect)
+    }
+
+    fn part_two(sections: &Self::Input) -> Result<impl Display, Self::Error> {
+        let overlap = sections
+            .iter()
+            .map(|(s1, s2)| s1.overlap(s2))
+            .map(|x| x as u32)
+            .sum::<u32>();
+        Ok(overlap)
+    }
+}
+
+#[derive(Debug)]
+pub struct Section {
+    start: u32,
+    end: u32,
+}
+
+impl Section {
+    fn from_string(range: &str) -> Section {
+        let mut arr = range.split('-').filter_map(|n| n.parse::<u32>().ok());
+        let start = arr.next().unwrap();
+        let end = arr.next().unwrap();
+        Section { start, end }
+    }
+
+    fn intersect(&self, other: &Section) -> bool {
+        self.start <= other.start && self.end >= other.end
+            || other.start <= self.start && other.end >= self.end
+    }
+
+    fn as_range(&self) -> ops::RangeInclusive<u32> {
+        self.start..=self.end
+    }
+
+    fn overlap(&self, other: &Section) -> bool {
+        let mut self_range = self.as_range();
+        let other_range = other.as_range();
+        self_range.any(|v| other_range.contains(&v))
+    }
+}
@@ -0,0 +1,119 @@
+use common::{ParseError, Solution};
+use std::{collections::HashMap, convert::Infallible, fmt::Display};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    /// Stacks of crates by their number, and the rearrangement procedure
+    type Input = (HashMap<usize, Vec<char>>, Vec<Procedure>);
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let column_line = input
+            .lines()
+            .enumerate()
+            .find(|&(_, line)| line.starts_with(" 1"))
+            .unwrap()
+            .0;
+
+        let table = get_table(column_line, input);
+
+        let procedures = input
+            .lines()
+            .skip(column_line + 1)
+            .filter(|line| !line.is_empty())
+            .map(Procedure::parse)
+            .collect();
+        Ok((table, procedures))
+    }
+
+    fn part_one((table, procedures): &Self::Input) -> Result<impl Display, Self::Error> {
+        let mut table = table.clone();
+
+        procedures.iter().for_each(|procedure| {
+            for _ in 0..procedure.qty {
+                let stack = table.get_mut(&procedure.from).unwrap();
+                let item = stack.pop().unwrap();
+                let stack = table.get_mut(&procedure.to).unwrap();
+                stack.push(item);
+            }
+        });
+
+        let output = (1..=table.len())
+            .map(|key| table.get(&key).unwrap().last().unwrap())
+            .collect::<String>();
+        Ok(output)
+    }
+
+    fn part_two((table, procedures): &Self::Input) -> Result<impl Display, Self::Error> {
+        let mut table = table.clone();
+
+        procedures.iter().for_each(|procedure| {
+            let stack = table.get(&procedure.from).unwrap();
+            let (stack, items) = stack.split_at(stack.len() - procedure.qty);
+            let mut items = items.to_vec();
+            table.insert(procedure.from, stack.to_vec());
+            let stack = table.get_mut(&procedure.to).unwrap();
+            let mut stack = stack.clone();
+            stack.append(&mut items);
+            table.insert(procedure.to, stack);
+        });
+
+        let output = (1..=table.len())
+            .map(|key| table.get(&key).unwrap().last().unwrap())
+            .collect::<String>();
+        Ok(output)
+    }
+}
+
+fn get_table(column_line: usize, input: &str) -> HashMap<usize, Vec<char>> {
+    let columns = input
+        .lines()
+        .nth(column_line)
+        .unwrap()
+        .chars()
+        .enumerate()
+        .filter_map(|(u, c)| if c.is_numeric() { Some(u) } else { None })
+        .collect::<Vec<_>>();
+
+    let table = input.lines().collect::<Vec<_>>();
+    let table = table.split_at(column_line).0.to_vec();
+
+    let mut hash_table = HashMap::new();
+
+    columns.iter().enumerate().for_each(|(idx, column)| {
+        let table = table
+            .iter()
+            .map(|&line| line.chars().nth(*column).unwrap())
+            .filter(|v| v.is_alphabetic())
+            .rev()
+            .collect::<Vec<char>>();
+        let key = idx + 1;
+        hash_table.insert(key, table);
+    });
+
+    hash_table
+}
+
+#[derive(Debug)]
+pub struct Procedure {
+    qty: usize,
+    from: usize,
+    to: usize,
+}
+
+impl Procedure {
+    fn parse(line: &str) -> Procedure {
+        let procedure = line
+            .split_whitespace()
+            .filter(|&word| word.parse::<u32>().is_ok())
+            .map(|char| char.parse::<usize>().unwrap())
+            .collect::<Vec<_>>();
+
+        let qty = procedure[0];
+        let from = procedure[1];
+        let to = procedure[2];
+
+        Procedure { qty, from, to }
+    }
+}
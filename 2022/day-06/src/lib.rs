@@ -0,0 +1,56 @@
+use circular_queue::CircularQueue;
+use common::{ParseError, Solution};
+use std::{collections::HashSet, convert::Infallible, fmt::Display};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The datastream buffer
+    type Input = String;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        let mut queue = CircularQueue::with_capacity(4);
+        let mut total_amount = 0;
+
+        for char in input.chars().take_while(|char| *char != '\n') {
+            queue.push(char);
+            total_amount += 1;
+
+            let mut uniq = HashSet::new();
+            queue.iter().for_each(|c| {
+                uniq.insert(c);
+            });
+            if uniq.len() == 4 {
+                break;
+            }
+        }
+
+        Ok(total_amount)
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        let max_queue_size = 14;
+        let mut queue = CircularQueue::with_capacity(max_queue_size);
+        let mut total_amount = 0;
+
+        for char in input.chars().take_while(|char| *char != '\n') {
+            queue.push(char);
+            total_amount += 1;
+
+            let mut uniq = HashSet::new();
+            for c in queue.iter() {
+                uniq.insert(*c);
+            }
+            if uniq.len() == max_queue_size {
+                break;
+            }
+        }
+
+        Ok(total_amount)
+    }
+}
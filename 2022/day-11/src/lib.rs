@@ -0,0 +1,75 @@
+pub mod monkey;
+
+use common::{ParseError, Solution, Unsolved};
+use monkey::Monkey;
+use std::{collections::VecDeque, fmt::Display};
+
+const ROUNDS: u8 = 1;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    /// The monkeys and the items each one starts with
+    type Input = Vec<Monkey>;
+    type Error = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let monkeys = input
+            .split_terminator("\n\n")
+            .map(Monkey::new)
+            .collect::<Vec<_>>();
+        Ok(monkeys)
+    }
+
+    fn part_one(monkeys: &Self::Input) -> Result<impl Display, Self::Error> {
+        let mut monkeys = monkeys.clone();
+
+        let mut inspected = vec![0; monkeys.len()];
+
+        for _round in 0..ROUNDS {
+            let mut changes: Vec<VecDeque<i32>> = Vec::new();
+            monkeys.iter().for_each(|_| {
+                changes.push(VecDeque::new());
+            });
+
+            for (index, monkey) in monkeys.iter_mut().enumerate() {
+                // println!("{:?}", monkey);
+
+                for change in changes[index].iter() {
+                    monkey.items.push_back(*change);
+                }
+
+                inspected[index] += monkey.items.len();
+                for item in monkey.items.iter() {
+                    let item = monkey.inspect(*item);
+                    // println!("{} item {}", monkey.name, item);
+                    let item = item / 3;
+                    // println!("{} item {}", monkey.name, item);
+                    let change_position = match monkey.test(item) {
+                        true => monkey.dest.0,
+                        false => monkey.dest.1,
+                    };
+                    // println!("{} change_position {}", monkey.name, change_position);
+                    let vec = changes.get_mut(change_position).unwrap();
+                    vec.push_back(item);
+                }
+                monkey.items = VecDeque::new();
+
+                // println!("{:?}", changes);
+            }
+
+            for (index, change) in changes.iter_mut().enumerate() {
+                monkeys[index].items.append(change);
+            }
+        }
+
+        inspected.sort();
+        let two_most_active = inspected.split_at(inspected.len() - 2).1;
+        let two_most_active = two_most_active[0] * two_most_active[1];
+        Ok(two_most_active)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<impl Display, Self::Error> {
+        Err::<usize, _>(Unsolved)
+    }
+}
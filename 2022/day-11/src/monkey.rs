@@ -1,7 +1,7 @@
-use std::collections::VecDeque;
 use meval::Expr;
+use std::collections::VecDeque;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct Monkey {
     pub name: String,
     pub items: VecDeque<i32>,
@@ -14,30 +14,30 @@ impl Monkey {
     pub fn new(monkey: &str) -> Self {
         let mut monkey = monkey.lines();
 
-        let name = monkey.next().unwrap()
-            .split(":")
+        let name = monkey.next().unwrap().split(":").next().unwrap().to_owned();
+
+        let starting_items = monkey
             .next()
             .unwrap()
-            .to_owned();
-
-        let starting_items = monkey.next().unwrap()
             .split(":")
-            .skip(1)
-            .next()
+            .nth(1)
             .unwrap()
             .split(",")
             .map(|v| v.trim().parse::<i32>().unwrap())
             .collect::<VecDeque<_>>();
 
-        let operation = monkey.next().unwrap()
-            .split("=")
-            .skip(1)
+        let operation = monkey
             .next()
             .unwrap()
+            .split("=")
+            .nth(1)
+            .unwrap()
             .trim()
             .to_string();
 
-        let test = monkey.next().unwrap()
+        let test = monkey
+            .next()
+            .unwrap()
             .split_whitespace()
             .last()
             .unwrap()
@@ -45,7 +45,9 @@ impl Monkey {
             .unwrap();
 
         let mut parse_case_line = || {
-            monkey.next().unwrap()
+            monkey
+                .next()
+                .unwrap()
                 .split_whitespace()
                 .last()
                 .unwrap()
@@ -70,7 +72,9 @@ impl Monkey {
     }
 
     pub fn inspect(&self, input: i32) -> i32 {
-        let expr = self.operation_raw_expr.parse::<Expr>()
+        let expr = self
+            .operation_raw_expr
+            .parse::<Expr>()
             .unwrap()
             .bind("old")
             .unwrap();
@@ -1,4 +1,26 @@
+use common::{ParseError, Solution, Unsolved};
 use matrix::{prelude::Compressed, Matrix};
+use std::fmt::Display;
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    /// Elevations of the heightmap, as their ASCII codes
+    type Input = Compressed<u8>;
+    type Error = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(get_matrix(input))
+    }
+
+    fn part_one(_: &Self::Input) -> Result<impl Display, Self::Error> {
+        Err::<usize, _>(Unsolved)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<impl Display, Self::Error> {
+        Err::<usize, _>(Unsolved)
+    }
+}
 
 pub fn get_matrix(input: &str) -> Compressed<u8> {
     let rows = input.matches('\n').count();
@@ -17,8 +39,9 @@ pub fn get_matrix(input: &str) -> Compressed<u8> {
 }
 
 pub fn find_char(matrix: &Compressed<u8>, char: char) -> (usize, usize) {
-    matrix.iter()
-    .find(|(_, _, &c)| c as char == char)
-    .map(|(x, y, _)| (x, y))
-    .unwrap()
+    matrix
+        .iter()
+        .find(|(_, _, &c)| c as char == char)
+        .map(|(x, y, _)| (x, y))
+        .unwrap()
 }
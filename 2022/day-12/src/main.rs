@@ -1,15 +1,17 @@
-use std::fs;
+use std::{error::Error, fs};
 
-use day_12::*;
+use common::Solution;
+use y2022_day_12::*;
 
 const INPUT: &str = "example.txt";
 
-fn main() {
-    let input = fs::read_to_string(INPUT).unwrap();
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = fs::read_to_string(INPUT)?;
 
-    let matrix = get_matrix(&input);
+    let matrix = Day12::parse(&input)?;
     let start = find_char(&matrix, 'S');
     let end = find_char(&matrix, 'E');
 
     println!("matrix {:?}\nS: {:?}, E {:?}", matrix, start, end);
+    Ok(())
 }
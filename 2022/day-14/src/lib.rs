@@ -0,0 +1,122 @@
+use common::{ParseError, Solution, Unsolved};
+use matrix::{prelude::Compressed, Element, Matrix};
+use std::fmt::Display;
+
+const SAND_HOLE: (usize, usize) = (0, 500);
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    /// The cave, with the rocks of the scan
+    type Input = Compressed<Stuff>;
+    type Error = Unsolved;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let input = input
+            .lines()
+            .map(|line| {
+                line.split("->")
+                    .map(|tuple| {
+                        let mut tuple = tuple
+                            .trim()
+                            .split_terminator(',')
+                            .map(|v| v.parse::<usize>().unwrap());
+                        let row = tuple.next().unwrap();
+                        let column = tuple.next().unwrap();
+                        (row, column)
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut matrix = build_matrix(&input);
+
+        for (col, row) in input.iter().flatten() {
+            matrix.set((*row, *col), Stuff::Rock);
+        }
+
+        Ok(matrix)
+    }
+
+    fn part_one(matrix: &Self::Input) -> Result<impl Display, Self::Error> {
+        let mut matrix = matrix.clone();
+
+        let mut sand_count = 0;
+        let mut sand = SAND_HOLE;
+        // Game loop
+        loop {
+            #[derive(Debug)]
+            enum Move {
+                Rest,
+                Down,
+                Left,
+                Right,
+            }
+
+            let (row, col) = sand;
+            if row == matrix.rows - 1 {
+                break;
+            }
+
+            let movement = if matrix.get((row + 1, col)) == Stuff::Void {
+                Move::Down
+            } else if matrix.get((row + 1, col - 1)) == Stuff::Void {
+                Move::Left
+            } else if matrix.get((row + 1, col + 1)) == Stuff::Void {
+                Move::Right
+            } else {
+                Move::Rest
+            };
+
+            sand = match movement {
+                Move::Down => (row + 1, col),
+                Move::Left => (row + 1, col - 1),
+                Move::Right => (row + 1, col + 1),
+                Move::Rest => {
+                    if sand == SAND_HOLE {
+                        break;
+                    }
+                    matrix.set(sand, Stuff::Sand);
+                    sand_count += 1;
+                    SAND_HOLE
+                }
+            };
+        }
+
+        Ok(sand_count)
+    }
+
+    fn part_two(_: &Self::Input) -> Result<impl Display, Self::Error> {
+        Err::<usize, _>(Unsolved)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stuff {
+    Void,
+    Rock,
+    Sand,
+}
+
+impl Element for Stuff {
+    fn zero() -> Self {
+        Self::Void
+    }
+
+    fn is_zero(&self) -> bool {
+        *self == Self::Void
+    }
+}
+
+fn build_matrix(input: &[Vec<(usize, usize)>]) -> Compressed<Stuff> {
+    let (xs, ys) = input
+        .iter()
+        .flatten()
+        .copied()
+        .unzip::<_, _, Vec<_>, Vec<_>>();
+
+    let rows = *ys.iter().max().unwrap() + 1;
+    let columns = *xs.iter().max().unwrap() + 1;
+
+    Compressed::zero((rows, columns))
+}
@@ -0,0 +1,296 @@
+//! Calibration values found in one pass over the document
+//!
+//! The words of a vocabulary are matched together by an Aho-Corasick
+//! automaton, so overlapping words like `oneight` are all seen without going
+//! back over the line. The automaton works on bytes, so words may be in any
+//! language and the document is read as it comes.
+
+use std::{
+    collections::VecDeque,
+    io::{self, BufRead},
+};
+
+/// Words standing for numbers, and the numbers they stand for
+///
+/// A word stands for the digits of its number, so `twelve` is a `1` when it
+/// is the first word of a line and a `2` when it is the last one.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Vocabulary {
+    words: Vec<(String, u32)>,
+}
+
+impl Vocabulary {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Digits from `0` to `9`
+    pub fn digits() -> Self {
+        (0..=9).fold(Self::new(), |vocabulary, digit| {
+            vocabulary.with(&digit.to_string(), digit)
+        })
+    }
+
+    /// Digits and the numbers from `one` to `nine` spelled in English
+    pub fn english() -> Self {
+        let words = [
+            "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+        ];
+        words
+            .into_iter()
+            .zip(1..)
+            .fold(Self::digits(), |vocabulary, (word, value)| {
+                vocabulary.with(word, value)
+            })
+    }
+
+    /// Adds `word` standing for `value`, in place of any previous meaning
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word` is empty
+    pub fn with(mut self, word: &str, value: u32) -> Self {
+        assert!(!word.is_empty(), "words should not be empty");
+        self.words.retain(|(w, _)| w != word);
+        self.words.push((word.to_string(), value));
+        self
+    }
+
+    pub fn words(&self) -> &[(String, u32)] {
+        &self.words
+    }
+}
+
+/// A word of the vocabulary ending at some state of the automaton
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Word {
+    len: usize,
+    value: u32,
+}
+
+/// First and last word of a line, found by an automaton over a vocabulary
+#[derive(Debug, Clone)]
+pub struct Decoder {
+    /// Next state from each state on each byte, failures already followed
+    next: Vec<[u32; 256]>,
+    /// Shortest word ending at each state
+    shortest: Vec<Option<Word>>,
+    /// Longest word ending at each state
+    longest: Vec<Option<Word>>,
+}
+
+impl Decoder {
+    pub fn new(vocabulary: &Vocabulary) -> Self {
+        // The trie of the words, 0 standing for no child since the root is
+        // nobody's child
+        let mut next = vec![[0u32; 256]];
+        let mut own = vec![None];
+        for (word, value) in vocabulary.words() {
+            let mut state = 0;
+            for &byte in word.as_bytes() {
+                if next[state][byte as usize] == 0 {
+                    next[state][byte as usize] = next.len() as u32;
+                    next.push([0; 256]);
+                    own.push(None);
+                }
+                state = next[state][byte as usize] as usize;
+            }
+            own[state] = Some(Word {
+                len: word.len(),
+                value: *value,
+            });
+        }
+
+        // Breadth first, so the failure of a state, which is shallower, is
+        // complete before the state takes its missing moves from it
+        let mut failure = vec![0; next.len()];
+        let mut shortest = own.clone();
+        let mut longest = own;
+        let mut queue = VecDeque::from([0]);
+        while let Some(state) = queue.pop_front() {
+            // Moves of the failure, where the missing ones of the state go
+            let fallbacks = match state {
+                0 => [0; 256],
+                _ => next[failure[state]],
+            };
+            for (byte, fallback) in fallbacks.into_iter().enumerate() {
+                let child = next[state][byte] as usize;
+                if child == 0 {
+                    next[state][byte] = fallback;
+                    continue;
+                }
+                let fail = fallback as usize;
+                failure[child] = fail;
+                // Words ending at the failure are suffixes, shorter than the
+                // word of the child itself
+                shortest[child] = shortest[fail].or(shortest[child]);
+                longest[child] = longest[child].or(longest[fail]);
+                queue.push_back(child);
+            }
+        }
+
+        Self {
+            next,
+            shortest,
+            longest,
+        }
+    }
+
+    /// Calibration value of a line, from the first digit of the word starting
+    /// first and the last digit of the word starting last, the longest of
+    /// those starting together
+    pub fn calibration(&self, line: &[u8]) -> Option<u32> {
+        let mut scan = Scan::default();
+        for &byte in line {
+            scan.push(self, byte);
+        }
+        scan.calibration()
+    }
+
+    /// Sum of the calibration values of every line read, lines without words
+    /// counting as `0`
+    pub fn sum(&self, mut reader: impl BufRead) -> io::Result<u64> {
+        let mut scan = Scan::default();
+        let mut sum = 0;
+        loop {
+            let buffer = reader.fill_buf()?;
+            if buffer.is_empty() {
+                break;
+            }
+            for &byte in buffer {
+                if byte == b'\n' {
+                    sum += u64::from(scan.calibration().unwrap_or_default());
+                    scan = Scan::default();
+                } else {
+                    scan.push(self, byte);
+                }
+            }
+            let read = buffer.len();
+            reader.consume(read);
+        }
+        Ok(sum + u64::from(scan.calibration().unwrap_or_default()))
+    }
+}
+
+/// Progress of the automaton through a line
+#[derive(Debug, Default)]
+struct Scan {
+    state: usize,
+    /// Bytes seen
+    position: usize,
+    /// Start and value of the word starting first
+    first: Option<(usize, u32)>,
+    /// Start and value of the word starting last
+    last: Option<(usize, u32)>,
+}
+
+impl Scan {
+    fn push(&mut self, decoder: &Decoder, byte: u8) {
+        self.state = decoder.next[self.state][byte as usize] as usize;
+        self.position += 1;
+        // Of the words ending here the longest starts first and the shortest
+        // starts last, and a word starting with another one wins over it
+        if let Some(word) = decoder.longest[self.state] {
+            let start = self.position - word.len;
+            if self.first.is_none_or(|(first, _)| start <= first) {
+                self.first = Some((start, word.value));
+            }
+        }
+        if let Some(word) = decoder.shortest[self.state] {
+            let start = self.position - word.len;
+            if self.last.is_none_or(|(last, _)| start >= last) {
+                self.last = Some((start, word.value));
+            }
+        }
+    }
+
+    fn calibration(&self) -> Option<u32> {
+        let (_, first) = self.first?;
+        let (_, last) = self.last?;
+        Some(leading_digit(first) * 10 + last % 10)
+    }
+}
+
+fn leading_digit(mut value: u32) -> u32 {
+    while value >= 10 {
+        value /= 10;
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decoder, Vocabulary};
+    use std::io::BufReader;
+
+    const EXAMPLE: &str = "\
+two1nine
+eightwothree
+abcone2threexyz
+xtwone3four
+4nineeightseven2
+zoneight234
+7pqrstsixteen
+";
+
+    #[test]
+    fn overlapping() {
+        let decoder = Decoder::new(&Vocabulary::english());
+        assert_eq!(decoder.calibration(b"oneight"), Some(18));
+        assert_eq!(decoder.calibration(b"eighthree"), Some(83));
+        assert_eq!(decoder.calibration(b"twone"), Some(21));
+        assert_eq!(decoder.calibration(b"sevenine"), Some(79));
+        assert_eq!(decoder.calibration(b"ninine"), Some(99));
+        assert_eq!(decoder.calibration(b"nothing"), None);
+    }
+
+    #[test]
+    fn digits_only() {
+        let decoder = Decoder::new(&Vocabulary::digits());
+        assert_eq!(decoder.calibration(b"two1nine"), Some(11));
+        assert_eq!(decoder.calibration(b"a0b"), Some(0));
+        assert_eq!(decoder.calibration(b"eightwothree"), None);
+    }
+
+    #[test]
+    fn other_words() {
+        let german = [
+            "eins", "zwei", "drei", "vier", "fünf", "sechs", "sieben", "acht", "neun",
+        ]
+        .into_iter()
+        .zip(1..)
+        .fold(Vocabulary::digits(), |vocabulary, (word, value)| {
+            vocabulary.with(word, value)
+        });
+        let decoder = Decoder::new(&german);
+        assert_eq!(decoder.calibration("xfünfzehn".as_bytes()), Some(55));
+        assert_eq!(decoder.calibration("dreins".as_bytes()), Some(31));
+
+        let vocabulary = Vocabulary::english().with("zero", 0).with("twelve", 12);
+        let decoder = Decoder::new(&vocabulary);
+        assert_eq!(decoder.calibration(b"zerone"), Some(1));
+        assert_eq!(decoder.calibration(b"xtwelvex"), Some(12));
+        assert_eq!(decoder.calibration(b"3twelve"), Some(32));
+        assert_eq!(decoder.calibration(b"twelve3"), Some(13));
+    }
+
+    #[test]
+    fn nested_words() {
+        let vocabulary = Vocabulary::english().with("seventeen", 17);
+        let decoder = Decoder::new(&vocabulary);
+        assert_eq!(decoder.calibration(b"seventeen"), Some(17));
+        assert_eq!(decoder.calibration(b"sevenx"), Some(77));
+        assert_eq!(decoder.calibration(b"xseventeenine"), Some(19));
+    }
+
+    #[test]
+    fn stream() {
+        let decoder = Decoder::new(&Vocabulary::english());
+        assert_eq!(decoder.sum(EXAMPLE.as_bytes()).unwrap(), 281);
+        // Words split across reads
+        let reader = BufReader::with_capacity(1, EXAMPLE.as_bytes());
+        assert_eq!(decoder.sum(reader).unwrap(), 281);
+        let reader = BufReader::with_capacity(3, EXAMPLE.trim_end().as_bytes());
+        assert_eq!(decoder.sum(reader).unwrap(), 281);
+    }
+}
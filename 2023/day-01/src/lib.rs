@@ -0,0 +1,121 @@
+use common::{ParseError, Solution};
+use decoder::{Decoder, Vocabulary};
+use lazy_static::lazy_static;
+use std::{fmt::Display, io};
+
+pub mod decoder;
+
+lazy_static! {
+    static ref DIGITS: Decoder = Decoder::new(&Vocabulary::digits());
+    static ref ENGLISH: Decoder = Decoder::new(&Vocabulary::english());
+}
+
+pub fn get_calibration(line: &str) -> u8 {
+    calibration(&DIGITS, line)
+}
+
+pub fn get_fancy_calibration(line: &str) -> u8 {
+    calibration(&ENGLISH, line)
+}
+
+fn calibration(decoder: &Decoder, line: &str) -> u8 {
+    let value = decoder.calibration(line.as_bytes()).unwrap_or_default();
+    // Two digits at most
+    value as u8
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = String;
+    type Error = io::Error;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        DIGITS.sum(input.as_bytes())
+    }
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Self::Error> {
+        ENGLISH.sum(input.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_one {
+        use crate::get_calibration;
+
+        #[test]
+        fn first() {
+            let value = get_calibration("1abc2");
+            assert_eq!(value, 12)
+        }
+
+        #[test]
+        fn second() {
+            let value = get_calibration("pqr3stu8vwx");
+            assert_eq!(value, 38)
+        }
+
+        #[test]
+        fn third() {
+            let value = get_calibration("a1b2c3d4e5f");
+            assert_eq!(value, 15)
+        }
+
+        #[test]
+        fn fourth() {
+            let value = get_calibration("treb7uchet");
+            assert_eq!(value, 77)
+        }
+    }
+
+    mod part_two {
+        use crate::get_fancy_calibration;
+
+        #[test]
+        fn t1() {
+            let value = get_fancy_calibration("two1nine");
+            assert_eq!(value, 29)
+        }
+
+        #[test]
+        fn t2() {
+            let value = get_fancy_calibration("eightwothree");
+            assert_eq!(value, 83)
+        }
+
+        #[test]
+        fn t3() {
+            let value = get_fancy_calibration("abcone2threexyz");
+            assert_eq!(value, 13)
+        }
+
+        #[test]
+        fn t4() {
+            let value = get_fancy_calibration("xtwone3four");
+            assert_eq!(value, 24)
+        }
+
+        #[test]
+        fn t5() {
+            let value = get_fancy_calibration("4nineeightseven2");
+            assert_eq!(value, 42)
+        }
+
+        #[test]
+        fn t6() {
+            let value = get_fancy_calibration("zoneight234");
+            assert_eq!(value, 14)
+        }
+
+        #[test]
+        fn t7() {
+            let value = get_fancy_calibration("7pqrstsixteen");
+            assert_eq!(value, 76)
+        }
+    }
+}
@@ -0,0 +1,323 @@
+use common::{ParseError, Solution};
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+const GAME_SET_TO_CHECK: GameSet = GameSet {
+    red: 12,
+    green: 13,
+    blue: 14,
+};
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<Game>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| line.parse::<Game>().map_err(|err| err.within(input, line)))
+            .collect()
+    }
+
+    fn part_one(games: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(games
+            .iter()
+            .filter(|game| game.possible(&GAME_SET_TO_CHECK))
+            .fold(0, |acc, game| acc + game.id))
+    }
+
+    fn part_two(games: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(games.iter().map(|game| game.minimum().power()).sum::<u64>())
+    }
+}
+
+#[derive(Debug, PartialEq, Default)]
+pub struct GameSet {
+    red: u8,
+    green: u8,
+    blue: u8,
+}
+
+impl GameSet {
+    pub fn power(&self) -> u64 {
+        self.red as u64 * self.green as u64 * self.blue as u64
+    }
+}
+
+impl FromStr for GameSet {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut game_set = GameSet::default();
+
+        for cubes in s.split(',') {
+            let mut words = cubes.split_whitespace();
+            let num = words
+                .next()
+                .ok_or_else(|| ParseError::at(s, cubes, "a number of cubes"))?;
+            let num = num
+                .parse::<u8>()
+                .map_err(|_| ParseError::at(s, num, "a number of cubes"))?;
+            let color = words
+                .next()
+                .ok_or_else(|| ParseError::after(s, cubes, "a cube color"))?;
+            match color {
+                "red" => game_set.red = num,
+                "green" => game_set.green = num,
+                "blue" => game_set.blue = num,
+                _ => return Err(ParseError::at(s, color, "`red`, `green` or `blue`")),
+            };
+        }
+
+        Ok(game_set)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Game {
+    id: u32,
+    sets: Vec<GameSet>,
+}
+
+impl Game {
+    pub fn possible(&self, against: &GameSet) -> bool {
+        self.sets.iter().all(|set| {
+            against.red >= set.red && against.blue >= set.blue && against.green >= set.green
+        })
+    }
+
+    pub fn minimum(&self) -> GameSet {
+        let mut game_set = GameSet::default();
+        self.sets.iter().for_each(|game| {
+            game_set.red = game_set.red.max(game.red);
+            game_set.green = game_set.green.max(game.green);
+            game_set.blue = game_set.blue.max(game.blue);
+        });
+        game_set
+    }
+}
+
+impl FromStr for Game {
+    type Err = ParseError;
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (header, sets) = line
+            .split_once(':')
+            .ok_or_else(|| ParseError::after(line, line, "`:`"))?;
+
+        let id = header
+            .split_whitespace()
+            .find_map(|str| str.parse::<u32>().ok())
+            .ok_or_else(|| ParseError::at(line, header, "a game id"))?;
+
+        let sets = sets
+            .split(';')
+            .map(|s| {
+                let s = s.trim();
+                s.parse::<GameSet>().map_err(|err| err.within(line, s))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Game { id, sets })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    mod part_one {
+        use crate::{Game, GameSet};
+        use common::ParseError;
+
+        const GAME_SET_TO_CHECK: GameSet = GameSet {
+            red: 12,
+            green: 13,
+            blue: 14,
+        };
+
+        #[test]
+        fn parse() {
+            let row = "Game 11: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green";
+            let game = row.parse::<Game>().unwrap();
+            let expected = Game {
+                id: 11,
+                sets: vec![
+                    GameSet {
+                        blue: 3,
+                        red: 4,
+                        green: 0,
+                    },
+                    GameSet {
+                        red: 1,
+                        green: 2,
+                        blue: 6,
+                    },
+                    GameSet {
+                        green: 2,
+                        blue: 0,
+                        red: 0,
+                    },
+                ],
+            };
+            assert_eq!(game, expected);
+        }
+
+        #[test]
+        fn parse_errors() {
+            let err = "Game 1: 3 blue; 2 purple".parse::<Game>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 18..24, "`red`, `green` or `blue`"));
+
+            let err = "Game 1: 3 blue, x red".parse::<Game>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 16..17, "a number of cubes"));
+
+            let err = "Game 1 3 blue".parse::<Game>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 13..14, "`:`"));
+        }
+
+        #[test]
+        fn t1() {
+            let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+                .parse::<Game>()
+                .unwrap();
+            assert!(game.possible(&GAME_SET_TO_CHECK))
+        }
+
+        #[test]
+        fn t2() {
+            let game = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue"
+                .parse::<Game>()
+                .unwrap();
+            assert!(game.possible(&GAME_SET_TO_CHECK))
+        }
+
+        #[test]
+        fn t3() {
+            let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+                .parse::<Game>()
+                .unwrap();
+            assert!(!game.possible(&GAME_SET_TO_CHECK))
+        }
+
+        #[test]
+        fn t4() {
+            let game = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+                .parse::<Game>()
+                .unwrap();
+            assert!(!game.possible(&GAME_SET_TO_CHECK))
+        }
+
+        #[test]
+        fn t5() {
+            let game = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
+                .parse::<Game>()
+                .unwrap();
+            assert!(game.possible(&GAME_SET_TO_CHECK))
+        }
+
+        #[test]
+        fn sum() {
+            let test_input = "
+                Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+                Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+                Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+                Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+                Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green
+            ";
+            let games = test_input
+                .lines()
+                .filter(|line| !line.trim().is_empty())
+                .map(|line| line.parse::<Game>().unwrap())
+                .collect::<Vec<_>>();
+            let possible_games_id_sum = games
+                .iter()
+                .filter(|game| game.possible(&GAME_SET_TO_CHECK))
+                .fold(0, |acc, game| acc + game.id);
+            assert_eq!(possible_games_id_sum, 8)
+        }
+    }
+
+    mod part_two {
+        use crate::{Game, GameSet};
+
+        #[test]
+        fn t1() {
+            let game = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(
+                game.minimum(),
+                GameSet {
+                    red: 4,
+                    green: 2,
+                    blue: 6
+                }
+            )
+        }
+
+        #[test]
+        fn t2() {
+            let game = "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(
+                game.minimum(),
+                GameSet {
+                    red: 1,
+                    green: 3,
+                    blue: 4
+                }
+            )
+        }
+
+        #[test]
+        fn t3() {
+            let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(
+                game.minimum(),
+                GameSet {
+                    red: 20,
+                    green: 13,
+                    blue: 6
+                }
+            )
+        }
+
+        #[test]
+        fn t4() {
+            let game = "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(
+                game.minimum(),
+                GameSet {
+                    red: 14,
+                    green: 3,
+                    blue: 15
+                }
+            )
+        }
+
+        #[test]
+        fn t5() {
+            let game = "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(
+                game.minimum(),
+                GameSet {
+                    red: 6,
+                    green: 3,
+                    blue: 2
+                }
+            )
+        }
+
+        #[test]
+        fn power() {
+            let game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+                .parse::<Game>()
+                .unwrap();
+            assert_eq!(game.minimum().power(), 1560)
+        }
+    }
+}
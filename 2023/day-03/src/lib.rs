@@ -0,0 +1,413 @@
+use common::{ParseError, Solution};
+use matrix::{prelude::Compressed, Element, Matrix, Size};
+use std::{convert::Infallible, fmt::Display, str::FromStr};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = EngineSchematic;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<EngineSchematic>()
+    }
+
+    fn part_one(engine_schematic: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(engine_schematic
+            .symbols()
+            .into_iter()
+            .flat_map(|symbol| engine_schematic.get_adjacent_numbers(&symbol))
+            .sum::<u32>())
+    }
+
+    fn part_two(engine_schematic: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(engine_schematic
+            .symbols()
+            .into_iter()
+            .filter(|symbol| symbol.is_gear)
+            .map(|symbol| engine_schematic.get_adjacent_numbers(&symbol))
+            .filter(|nums| nums.len() == 2)
+            .map(|nums| nums[0] * nums[1])
+            .sum::<u32>())
+    }
+}
+
+type Coordinates = (usize, usize);
+
+#[derive(Debug)]
+pub struct EngineSchematic(Compressed<Token>);
+
+impl EngineSchematic {
+    /// Rows and columns of the schematic, every row being as wide as the
+    /// first one
+    fn get_input_size(input: &str) -> Result<Coordinates, ParseError> {
+        let first_line = input
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(0, 0..1, "a line of the schematic"))?;
+        let width = first_line.chars().count();
+        for line in input.lines() {
+            if line.is_empty() {
+                return Err(ParseError::at(input, line, "a line of the schematic"));
+            }
+            if line.chars().count() != width {
+                let expected = format!("a line of {width} characters, like the first one");
+                return Err(ParseError::at(input, line, expected));
+            }
+        }
+        Ok((input.lines().count(), width))
+    }
+
+    fn new(size: (usize, usize)) -> Self {
+        Self(Compressed::zero(size))
+    }
+
+    fn insert_line(&mut self, line_num: usize, line: Vec<Token>) {
+        let mut idx = 0;
+        for token in line.into_iter() {
+            match token {
+                Token::None => (),
+                Token::Symbol { .. } => self.0.set((line_num, idx), token),
+                Token::Number { len, .. } => {
+                    for _ in 0..len {
+                        self.0.set((line_num, idx), token);
+                        idx += 1;
+                    }
+                    idx -= 1;
+                }
+            };
+            idx += 1;
+        }
+    }
+
+    fn symbols(&self) -> Vec<Symbol> {
+        let mut symbols = Vec::new();
+        for x in 0..self.0.rows() {
+            for y in 0..self.0.columns() {
+                let token = self.0.get((x, y));
+                if let Token::Symbol(is_gear) = token {
+                    symbols.push(Symbol {
+                        coordinates: (x, y),
+                        is_gear,
+                    })
+                }
+            }
+        }
+        symbols
+    }
+
+    fn get_adjacent_numbers(&self, symbol: &Symbol) -> Vec<u32> {
+        let mut numbers = Vec::new();
+        let mut ids = Vec::new();
+
+        let Symbol {
+            coordinates: (x, y),
+            ..
+        } = symbol;
+
+        let x_start = x.checked_sub(1).unwrap_or_default();
+        let x_end = (x + 1).min(self.0.rows() - 1);
+        let y_start = y.checked_sub(1).unwrap_or_default();
+        let y_end = (y + 1).min(self.0.columns() - 1);
+        for x in x_start..=x_end {
+            for y in y_start..=y_end {
+                let token = self.0.get((x, y));
+                if let Token::Number { id, value, .. } = token {
+                    if !ids.contains(&id) {
+                        numbers.push(value);
+                        ids.push(id);
+                    }
+                }
+            }
+        }
+
+        numbers
+    }
+}
+
+impl FromStr for EngineSchematic {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let size = Self::get_input_size(input)?;
+        let mut parser = Parser::default();
+        let mut matrix = Self::new(size);
+        for (num, line) in input.lines().enumerate() {
+            let tokens = parser
+                .parse_line(line)
+                .map_err(|err| err.within(input, line))?;
+            matrix.insert_line(num, tokens);
+        }
+        Ok(matrix)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Symbol {
+    coordinates: Coordinates,
+    is_gear: bool,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Token {
+    None,
+    Symbol(bool),
+    Number { id: usize, value: u32, len: usize },
+}
+
+impl Element for Token {
+    fn zero() -> Self {
+        Token::None
+    }
+}
+
+#[derive(Debug, Default)]
+struct Parser {
+    current_id_number: usize,
+}
+
+impl Parser {
+    fn parse_line(&mut self, line: &str) -> Result<Vec<Token>, ParseError> {
+        let mut tokens = Vec::new();
+
+        let mut iter = line.char_indices().peekable();
+        while let Some(&(start, c)) = iter.peek() {
+            if c.is_numeric() {
+                let mut len = 0;
+                let mut end = start;
+                while let Some((i, c)) = iter.next_if(|(_, c)| c.is_numeric()) {
+                    len += 1;
+                    end = i + c.len_utf8();
+                }
+                let number = &line[start..end];
+                let value = number
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::at(line, number, "a part number"))?;
+                tokens.push(Token::Number {
+                    id: self.current_id_number,
+                    value,
+                    len,
+                });
+                self.current_id_number += 1;
+            } else {
+                iter.next();
+                if c == '.' {
+                    tokens.push(Token::None)
+                } else if c == '*' {
+                    tokens.push(Token::Symbol(true))
+                } else {
+                    tokens.push(Token::Symbol(false))
+                }
+            }
+        }
+
+        Ok(tokens)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    const INPUT: &str = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+
+    mod part_one {
+        use super::INPUT;
+        use crate::*;
+
+        #[test]
+        fn get_m_size() {
+            let m_size = EngineSchematic::get_input_size(INPUT).unwrap();
+            assert_eq!(m_size, (10, 10));
+        }
+
+        #[test]
+        fn parse_errors() {
+            let err = "".parse::<EngineSchematic>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 0..1, "a line of the schematic"));
+
+            let err = "1.\n\n.*\n".parse::<EngineSchematic>().unwrap_err();
+            assert_eq!(err, ParseError::new(1, 0..0, "a line of the schematic"));
+
+            let err = "\n1.\n".parse::<EngineSchematic>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 0..0, "a line of the schematic"));
+
+            let err = "467..\n..*\n".parse::<EngineSchematic>().unwrap_err();
+            assert_eq!(
+                err,
+                ParseError::new(1, 0..3, "a line of 5 characters, like the first one")
+            );
+        }
+
+        #[test]
+        fn other_shapes() {
+            let matrix = "1 2 3\n".parse::<EngineSchematic>().unwrap();
+            assert_eq!(matrix.symbols().len(), 2);
+            assert_eq!(matrix.get_adjacent_numbers(&matrix.symbols()[0]), [1, 2]);
+
+            let matrix = "é\n".parse::<EngineSchematic>().unwrap();
+            assert_eq!(matrix.get_adjacent_numbers(&matrix.symbols()[0]), []);
+
+            let matrix = "..\n.*\n.9\n".parse::<EngineSchematic>().unwrap();
+            assert_eq!(matrix.get_adjacent_numbers(&matrix.symbols()[0]), [9]);
+        }
+
+        #[test]
+        fn parse_line() {
+            let mut parser = Parser::default();
+            let line = "617*..63..";
+            let output = vec![
+                Token::Number {
+                    id: 0,
+                    value: 617,
+                    len: 3,
+                },
+                Token::Symbol(true),
+                Token::None,
+                Token::None,
+                Token::Number {
+                    id: 1,
+                    value: 63,
+                    len: 2,
+                },
+                Token::None,
+                Token::None,
+            ];
+            assert_eq!(parser.parse_line(line), Ok(output))
+        }
+
+        #[test]
+        fn parse_line_overflow() {
+            let mut parser = Parser::default();
+            let line = "..*99999999999.";
+            assert_eq!(
+                parser.parse_line(line),
+                Err(ParseError::new(0, 3..14, "a part number"))
+            )
+        }
+
+        #[test]
+        fn insert_line() {
+            let size = (1, 10);
+            let mut engine_schematic = EngineSchematic::new(size);
+            let mut parser = Parser::default();
+            let line = parser.parse_line("617*..63..").unwrap();
+            engine_schematic.insert_line(0, line);
+
+            let mut matrix = Compressed::zero(size);
+            let t1 = Token::Number {
+                id: 0,
+                value: 617,
+                len: 3,
+            };
+            matrix.set((0, 0), t1);
+            matrix.set((0, 1), t1);
+            matrix.set((0, 2), t1);
+            matrix.set((0, 3), Token::Symbol(true));
+            let t2 = Token::Number {
+                id: 1,
+                value: 63,
+                len: 2,
+            };
+            matrix.set((0, 6), t2);
+            matrix.set((0, 7), t2);
+
+            assert_eq!(matrix, engine_schematic.0);
+        }
+
+        #[test]
+        fn symbols() {
+            let mut matrix = EngineSchematic::new((2, 2));
+            matrix.0.set((0, 0), Token::Symbol(false));
+            matrix.0.set((1, 1), Token::Symbol(false));
+            let symbols = matrix.symbols();
+            assert_eq!(
+                symbols,
+                vec![
+                    Symbol {
+                        coordinates: (0, 0),
+                        is_gear: false
+                    },
+                    Symbol {
+                        coordinates: (1, 1),
+                        is_gear: false
+                    }
+                ]
+            );
+        }
+
+        #[test]
+        fn get_adjacent_numbers() {
+            let mut matrix = EngineSchematic::new((3, 3));
+            matrix.0.set((1, 1), Token::Symbol(false));
+            matrix.0.set(
+                (0, 0),
+                Token::Number {
+                    id: 0,
+                    value: 12,
+                    len: 2,
+                },
+            );
+            matrix.0.set(
+                (1, 0),
+                Token::Number {
+                    id: 0,
+                    value: 12,
+                    len: 2,
+                },
+            );
+            matrix.0.set(
+                (2, 1),
+                Token::Number {
+                    id: 1,
+                    value: 3,
+                    len: 1,
+                },
+            );
+            let mut adjacents = matrix.get_adjacent_numbers(&Symbol {
+                coordinates: (1, 1),
+                is_gear: false,
+            });
+            adjacents.sort();
+            assert_eq!(adjacents, vec![3, 12])
+        }
+
+        #[test]
+        fn sum() {
+            let matrix = INPUT.parse::<EngineSchematic>().unwrap();
+            let sum = matrix
+                .symbols()
+                .into_iter()
+                .flat_map(|s| matrix.get_adjacent_numbers(&s))
+                .sum::<u32>();
+            assert_eq!(sum, 4361);
+        }
+    }
+
+    mod part_two {
+        use super::INPUT;
+        use crate::EngineSchematic;
+
+        #[test]
+        fn get_gear() {
+            let matrix = INPUT.parse::<EngineSchematic>().unwrap();
+            let gears = matrix
+                .symbols()
+                .into_iter()
+                .filter(|symbol| symbol.is_gear)
+                .map(|symbol| matrix.get_adjacent_numbers(&symbol))
+                .filter(|nums| nums.len() == 2)
+                .map(|nums| nums[0] * nums[1])
+                .sum::<u32>();
+            assert_eq!(gears, 467835);
+        }
+    }
+}
@@ -0,0 +1,599 @@
+use common::{ParseError, Solution};
+use itertools::Itertools;
+use std::{
+    error::Error,
+    fmt::{self, Display},
+    ops::Range,
+    str::FromStr,
+};
+
+pub mod range;
+pub mod trace;
+
+use trace::{Trace, TraceBack};
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = Almanac;
+    type Error = NoSeeds;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Almanac>()
+    }
+
+    fn part_one(almanac: &Self::Input) -> Result<impl Display, Self::Error> {
+        almanac.find_locations().into_iter().min().ok_or(NoSeeds)
+    }
+
+    fn part_two(almanac: &Self::Input) -> Result<impl Display, Self::Error> {
+        almanac.find_lowest_location_using_range().ok_or(NoSeeds)
+    }
+}
+
+/// The almanac lists no seed, so no seed has a location
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoSeeds;
+
+impl Display for NoSeeds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "there are no seeds")
+    }
+}
+
+impl Error for NoSeeds {}
+
+/// Seeds and the maps between categories, kept in the order of the chain
+/// that goes from `seed` to the last category, `maps[i]` converting
+/// `categories[i]` into `categories[i + 1]`
+#[derive(Debug, PartialEq)]
+pub struct Almanac {
+    seeds: Vec<u64>,
+    categories: Vec<String>,
+    maps: Vec<Vec<(u64, u64, u64)>>,
+}
+
+impl Almanac {
+    /// Names of the categories, from `seed` to the last one
+    pub fn categories(&self) -> &[String] {
+        &self.categories
+    }
+
+    fn category_index(&self, category: &str) -> Option<usize> {
+        self.categories.iter().position(|c| c == category)
+    }
+
+    /// Converts `numbers` of the `from` category into the `to` category
+    ///
+    /// Returns `None` if any of the categories is not in the almanac or if
+    /// `to` comes before `from` in the chain.
+    pub fn convert(&self, from: &str, to: &str, numbers: &[u64]) -> Option<Vec<u64>> {
+        let (from, to) = (self.category_index(from)?, self.category_index(to)?);
+        if to < from {
+            return None;
+        }
+        let converted = (from..to).fold(numbers.to_vec(), |numbers, i| {
+            self.convert_categories(i, &numbers)
+        });
+        Some(converted)
+    }
+
+    /// Ranges of the `to` category that `ranges` of the `from` category are
+    /// converted into, see [`Almanac::convert`]
+    pub fn convert_ranges(
+        &self,
+        from: &str,
+        to: &str,
+        ranges: &[Range<u64>],
+    ) -> Option<Vec<Range<u64>>> {
+        let (from, to) = (self.category_index(from)?, self.category_index(to)?);
+        if to < from {
+            return None;
+        }
+        let converted = self.maps[from..to]
+            .iter()
+            .fold(range::merge(ranges.to_vec()), |ranges, map| {
+                range::forward(map, &ranges)
+            });
+        Some(converted)
+    }
+
+    fn convert_categories(&self, destination_category_idx: usize, numbers: &[u64]) -> Vec<u64> {
+        let destination = self.maps.get(destination_category_idx).unwrap();
+
+        let mut found = Vec::new();
+
+        for number in numbers {
+            let initial_len = found.len();
+            for (d_range_start, s_range_start, len) in destination {
+                if number >= s_range_start && number - s_range_start < *len {
+                    let new_number = number - s_range_start + d_range_start;
+                    found.push(new_number)
+                }
+            }
+            if initial_len == found.len() {
+                found.push(*number);
+            }
+        }
+
+        found
+    }
+
+    pub fn find_locations(&self) -> Vec<u64> {
+        let mut locations = self.seeds.clone();
+
+        for i in 0..self.maps.len() {
+            locations = self.convert_categories(i, &locations)
+        }
+
+        locations
+    }
+
+    /// Seeds read as pairs of range start and length, as done in part two
+    pub fn seed_ranges(&self) -> Vec<Range<u64>> {
+        let ranges = self
+            .seeds
+            .iter()
+            .tuples()
+            .map(|(&start, &len)| start..start + len)
+            .collect();
+        range::merge(ranges)
+    }
+
+    /// Locations of every seed in `seeds`
+    pub fn map_ranges(&self, seeds: &[Range<u64>]) -> Vec<Range<u64>> {
+        self.maps
+            .iter()
+            .fold(range::merge(seeds.to_vec()), |ranges, map| {
+                range::forward(map, &ranges)
+            })
+    }
+
+    /// Every seed, listed or not, whose location is in `locations`
+    pub fn unmap_ranges(&self, locations: &[Range<u64>]) -> Vec<Range<u64>> {
+        self.maps
+            .iter()
+            .rev()
+            .fold(range::merge(locations.to_vec()), |ranges, map| {
+                range::backward(map, &ranges)
+            })
+    }
+
+    /// Every seed, listed or not, that ends up at `location`
+    ///
+    /// Ranges can't hold `u64::MAX`, so nothing ends up there.
+    pub fn seeds_producing(&self, location: u64) -> Vec<Range<u64>> {
+        let locations = location..location.saturating_add(1);
+        self.unmap_ranges(&[locations])
+    }
+
+    /// Walks the maps backwards from `locations`, keeping the ranges found
+    /// in every category on the way
+    pub fn trace_back(&self, locations: Range<u64>) -> TraceBack {
+        let mut ranges = vec![range::merge(vec![locations])];
+        for map in self.maps.iter().rev() {
+            let previous = range::backward(map, &ranges[ranges.len() - 1]);
+            ranges.push(previous);
+        }
+        ranges.reverse();
+        TraceBack {
+            categories: self.categories.clone(),
+            ranges,
+        }
+    }
+
+    /// Value of `seed` in every category until its location
+    pub fn trace(&self, seed: u64) -> Trace {
+        let mut values = vec![seed];
+        for i in 0..self.maps.len() {
+            let next = self.convert_categories(i, &values[values.len() - 1..]);
+            values.push(next[0]);
+        }
+        Trace {
+            categories: self.categories.clone(),
+            values,
+        }
+    }
+
+    /// Trace of the listed seed with the lowest location, the answer of part
+    /// two
+    pub fn winning_seed(&self) -> Option<Trace> {
+        let location = self.map_ranges(&self.seed_ranges()).first()?.start;
+        let seeds = self.seed_ranges_landing_in(location..location + 1);
+        Some(self.trace(seeds.first()?.start))
+    }
+
+    /// Ranges of the listed seeds that land in `locations`
+    pub fn seed_ranges_landing_in(&self, locations: Range<u64>) -> Vec<Range<u64>> {
+        range::intersect(&self.unmap_ranges(&[locations]), &self.seed_ranges())
+    }
+
+    /// Lowest location of the seed ranges, if they have any seed
+    pub fn find_lowest_location_using_range(&self) -> Option<u64> {
+        self.map_ranges(&self.seed_ranges())
+            .first()
+            .map(|locations| locations.start)
+    }
+}
+
+impl FromStr for Almanac {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_number = |word: &str| {
+            word.parse::<u64>()
+                .map_err(|_| ParseError::at(s, word, "a number"))
+        };
+
+        let seeds_line = s.lines().next().unwrap_or(s);
+        let mut words = seeds_line.split_whitespace();
+        match words.next() {
+            Some("seeds:") => (),
+            word => return Err(ParseError::at(s, word.unwrap_or(seeds_line), "`seeds:`")),
+        }
+        let words = words.collect::<Vec<_>>();
+        let seeds = words
+            .iter()
+            .map(|word| parse_number(word))
+            .collect::<Result<Vec<_>, _>>()?;
+        if words.len() % 2 == 1 {
+            let last = words[words.len() - 1];
+            return Err(ParseError::after(
+                s,
+                last,
+                "the length of the last seed range",
+            ));
+        }
+        if seeds
+            .iter()
+            .tuples()
+            .any(|(start, len)| start.checked_add(*len).is_none())
+        {
+            return Err(ParseError::at(
+                s,
+                seeds_line,
+                "seed ranges ending below 2^64",
+            ));
+        }
+
+        let blocks = s.split_terminator("\n\n").skip(1);
+
+        let mut unordered = Vec::new();
+        for block in blocks {
+            let header = block.lines().next().unwrap_or(block);
+            let (source, destination) = header
+                .strip_suffix(" map:")
+                .and_then(|name| name.split_once("-to-"))
+                .ok_or_else(|| ParseError::at(s, header, "`<source>-to-<destination> map:`"))?;
+
+            let mut map = Vec::new();
+            for line in block.lines().skip(1) {
+                let (destination_range_start, source_range_start, range_len) = line
+                    .split_whitespace()
+                    .map(parse_number)
+                    .collect::<Result<Vec<_>, _>>()?
+                    .into_iter()
+                    .collect_tuple()
+                    .ok_or_else(|| {
+                        ParseError::at(s, line, "a destination start, source start and length")
+                    })?;
+                if destination_range_start.checked_add(range_len).is_none()
+                    || source_range_start.checked_add(range_len).is_none()
+                {
+                    return Err(ParseError::at(s, line, "ranges ending below 2^64"));
+                }
+                map.push((destination_range_start, source_range_start, range_len))
+            }
+            unordered.push((header, source, destination, map))
+        }
+
+        // Follows the maps from `seed`, each category must be converted by a
+        // single map and the chain can't come back to a category
+        let mut categories = vec!["seed".to_string()];
+        let mut maps = Vec::new();
+        loop {
+            let current = &categories[categories.len() - 1];
+            let mut next = unordered.iter().filter(|(_, source, ..)| source == current);
+            let Some(&(header, ..)) = next.next() else {
+                break;
+            };
+            if let Some((header, ..)) = next.next() {
+                return Err(ParseError::at(
+                    s,
+                    header,
+                    format!("a single map from `{current}`"),
+                ));
+            }
+
+            let i = unordered.iter().position(|(h, ..)| *h == header).unwrap();
+            let (header, _, destination, map) = unordered.remove(i);
+            if categories.iter().any(|c| c == destination) {
+                let expected =
+                    format!("a map to a new category, `{destination}` was already reached");
+                return Err(ParseError::at(s, header, expected));
+            }
+            categories.push(destination.to_string());
+            maps.push(map);
+        }
+
+        if let Some((header, ..)) = unordered.first() {
+            let last = &categories[categories.len() - 1];
+            return Err(ParseError::at(s, header, format!("a map from `{last}`")));
+        }
+
+        Ok(Self {
+            seeds,
+            categories,
+            maps,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Almanac, Day05, NoSeeds};
+    use common::{ParseError, Solution};
+    use std::{fs, str::FromStr};
+
+    fn get_input() -> String {
+        fs::read_to_string("./example.txt").unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let almanac = Almanac::from_str(&get_input()).unwrap();
+        assert_eq!(
+            almanac,
+            Almanac {
+                seeds: vec![79, 14, 55, 13],
+                categories: [
+                    "seed",
+                    "soil",
+                    "fertilizer",
+                    "water",
+                    "light",
+                    "temperature",
+                    "humidity",
+                    "location"
+                ]
+                .map(String::from)
+                .to_vec(),
+                maps: vec![
+                    vec![(50, 98, 2), (52, 50, 48)],
+                    vec![(0, 15, 37), (37, 52, 2), (39, 0, 15)],
+                    vec![(49, 53, 8), (0, 11, 42), (42, 0, 7), (57, 7, 4)],
+                    vec![(88, 18, 7), (18, 25, 70)],
+                    vec![(45, 77, 23), (81, 45, 19), (68, 64, 13)],
+                    vec![(0, 69, 1), (1, 0, 69)],
+                    vec![(60, 56, 37), (56, 93, 4)]
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "seeds: 79 14\n\nseed-to-soil map:\n50 98 x2\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(err, ParseError::new(3, 6..8, "a number"));
+
+        let err = "seeds: 79 14\n\nseed-to-soil map:\n50 98\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(3, 0..5, "a destination start, source start and length")
+        );
+
+        let err = "79 14\n".parse::<Almanac>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 0..2, "`seeds:`"));
+
+        let err = "seeds: 79 14\n\nseed to soil:\n50 98 2\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 0..13, "`<source>-to-<destination> map:`")
+        );
+
+        let err = "seeds: 1 2\n\n\n\nseed-to-soil map:\n1 2 3\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 0..0, "`<source>-to-<destination> map:`")
+        );
+
+        let err = "".parse::<Almanac>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 0..0, "`seeds:`"));
+
+        let err = "seeds: 1 2 3\n".parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(0, 12..13, "the length of the last seed range")
+        );
+    }
+
+    #[test]
+    fn parse_overflow() {
+        let err = "seeds: 18446744073709551615 2\n\nseed-to-soil map:\n50 98 2\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(0, 0..29, "seed ranges ending below 2^64")
+        );
+
+        let err = "seeds: 79 14\n\nseed-to-soil map:\n50 18446744073709551615 2\n"
+            .parse::<Almanac>()
+            .unwrap_err();
+        assert_eq!(err, ParseError::new(3, 0..25, "ranges ending below 2^64"));
+
+        let almanac =
+            "seeds: 18446744073709551613 2\n\nseed-to-soil map:\n0 18446744073709551613 2\n"
+                .parse::<Almanac>()
+                .unwrap();
+        assert_eq!(almanac.find_lowest_location_using_range(), Some(0));
+        assert_eq!(almanac.seeds_producing(u64::MAX), vec![]);
+    }
+
+    #[test]
+    fn parse_out_of_order() {
+        let input = get_input();
+        let mut blocks = input.trim_end().split("\n\n").collect::<Vec<_>>();
+        blocks[1..].reverse();
+        let almanac = blocks.join("\n\n").parse::<Almanac>().unwrap();
+        assert_eq!(almanac, get_almanac());
+    }
+
+    #[test]
+    fn parse_broken_chains() {
+        let broken = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nwater-to-light map:\n1 2 3\n";
+        let err = broken.parse::<Almanac>().unwrap_err();
+        assert_eq!(err, ParseError::new(5, 0..19, "a map from `soil`"));
+
+        let cyclic = "seeds: 1 2\n\nseed-to-soil map:\n1 2 3\n\nsoil-to-seed map:\n1 2 3\n";
+        let err = cyclic.parse::<Almanac>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(
+                5,
+                0..17,
+                "a map to a new category, `seed` was already reached"
+            )
+        );
+
+        let branching = "seeds: 1 2\n\nseed-to-soil map:\n\nseed-to-water map:\n";
+        let err = branching.parse::<Almanac>().unwrap_err();
+        assert_eq!(err, ParseError::new(4, 0..18, "a single map from `seed`"));
+    }
+
+    #[test]
+    fn convert_named_categories() {
+        let almanac = get_almanac();
+        assert_eq!(
+            almanac.convert("soil", "light", &[81, 14]),
+            Some(vec![74, 42])
+        );
+        assert_eq!(almanac.convert("seed", "location", &[79]), Some(vec![82]));
+        assert_eq!(almanac.convert("water", "water", &[5]), Some(vec![5]));
+        assert_eq!(almanac.convert("light", "soil", &[5]), None);
+        assert_eq!(almanac.convert("seed", "dirt", &[5]), None);
+
+        let lights = almanac.convert_ranges("soil", "light", &[81..82, 14..15]);
+        assert_eq!(lights, Some(vec![42..43, 74..75]));
+    }
+
+    fn get_almanac() -> Almanac {
+        get_input().parse::<Almanac>().unwrap()
+    }
+
+    #[test]
+    fn t1() {
+        let almanac = get_almanac();
+        let seeds = [79, 14, 55, 13];
+        let soil_numbers = almanac.convert_categories(0, &seeds);
+        assert_eq!(soil_numbers, vec![81, 14, 57, 13])
+    }
+
+    #[test]
+    fn t2() {
+        let almanac = get_almanac();
+        let locations = almanac.find_locations();
+        assert_eq!(locations, vec![82, 43, 86, 35]);
+    }
+
+    #[test]
+    fn t3() {
+        let almanac = get_almanac();
+        let lowest = almanac.find_lowest_location_using_range();
+        assert_eq!(lowest, Some(46));
+    }
+
+    #[test]
+    fn no_seeds() {
+        let almanac = "seeds:\n\nseed-to-soil map:\n50 98 2\n"
+            .parse::<Almanac>()
+            .unwrap();
+        assert_eq!(Day05::part_one(&almanac).map(|_| ()), Err(NoSeeds));
+        assert_eq!(Day05::part_two(&almanac).map(|_| ()), Err(NoSeeds));
+
+        // Ranges without any seed in them, which are still seeds in part one
+        let almanac = "seeds: 79 0 55 0\n\nseed-to-soil map:\n50 98 2\n"
+            .parse::<Almanac>()
+            .unwrap();
+        assert_eq!(almanac.find_lowest_location_using_range(), None);
+        assert_eq!(Day05::part_one(&almanac).unwrap().to_string(), "0");
+    }
+
+    #[test]
+    fn map_ranges() {
+        let almanac = get_almanac();
+        let seeds = [79..80, 14..15, 55..56, 13..14];
+        assert_eq!(
+            almanac.map_ranges(&seeds),
+            vec![35..36, 43..44, 82..83, 86..87]
+        );
+    }
+
+    #[test]
+    fn seed_ranges_landing_in() {
+        let almanac = get_almanac();
+        assert_eq!(almanac.seed_ranges(), vec![55..68, 79..93]);
+        assert_eq!(almanac.seed_ranges_landing_in(46..47), vec![82..83]);
+        assert_eq!(almanac.seed_ranges_landing_in(0..46), vec![]);
+
+        let everything = almanac.seed_ranges_landing_in(0..u64::MAX);
+        assert_eq!(everything, almanac.seed_ranges());
+    }
+
+    #[test]
+    fn trace() {
+        let almanac = get_almanac();
+        let trace = almanac.trace(79);
+        assert_eq!(trace.values, vec![79, 81, 81, 81, 74, 78, 78, 82]);
+        assert_eq!(
+            trace.to_string(),
+            "seed        79
+soil        81
+fertilizer  81
+water       81
+light       74
+temperature 78
+humidity    78
+location    82
+"
+        );
+    }
+
+    #[test]
+    fn trace_back() {
+        let almanac = get_almanac();
+        let trace = almanac.trace_back(46..47);
+        assert_eq!(trace.ranges.len(), 8);
+        assert_eq!(trace.ranges[7], vec![46..47]);
+        assert!(trace.seeds().iter().any(|r| r.contains(&82)));
+        assert_eq!(trace.seeds(), almanac.seeds_producing(46));
+        for (category, ranges) in almanac.trace(82).values.iter().zip(&trace.ranges) {
+            assert!(ranges.iter().any(|r| r.contains(category)));
+        }
+    }
+
+    #[test]
+    fn winning_seed() {
+        let almanac = get_almanac();
+        let trace = almanac.winning_seed().unwrap();
+        assert_eq!((trace.seed(), trace.location()), (82, 46));
+    }
+
+    #[test]
+    #[allow(clippy::single_range_in_vec_init)]
+    fn unmap_ranges() {
+        let almanac = get_almanac();
+        for seed in 0..100 {
+            let location = almanac.map_ranges(&[seed..seed + 1]);
+            let seeds = almanac.unmap_ranges(&location);
+            assert!(seeds.iter().any(|r| r.contains(&seed)));
+        }
+    }
+}
@@ -0,0 +1,146 @@
+//! Interval arithmetic on the almanac maps
+//!
+//! A map is a list of `(destination start, source start, length)` entries,
+//! numbers not covered by any source range are mapped to themselves. Ranges
+//! are half open and always kept sorted and merged.
+
+use std::ops::Range;
+
+pub type Map = [(u64, u64, u64)];
+
+/// Sorts `ranges`, dropping the empty ones and joining the ones that overlap
+/// or touch
+pub fn merge(mut ranges: Vec<Range<u64>>) -> Vec<Range<u64>> {
+    ranges.retain(|r| !r.is_empty());
+    ranges.sort_unstable_by_key(|r| r.start);
+
+    let mut merged: Vec<Range<u64>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Numbers present in both `a` and `b`
+pub fn intersect(a: &[Range<u64>], b: &[Range<u64>]) -> Vec<Range<u64>> {
+    let ranges = a
+        .iter()
+        .flat_map(|a| {
+            b.iter()
+                .map(move |b| a.start.max(b.start)..a.end.min(b.end))
+        })
+        .collect();
+    merge(ranges)
+}
+
+/// Splits `range` into the part inside `window` and the parts before and
+/// after it
+fn split(range: &Range<u64>, window: &Range<u64>) -> (Range<u64>, [Range<u64>; 2]) {
+    let inside = range.start.max(window.start)..range.end.min(window.end);
+    let before = range.start..range.end.min(window.start);
+    let after = range.start.max(window.end)..range.end;
+    (inside, [before, after])
+}
+
+/// Moves `range`, which must be inside `from`, to the same place in `to`
+fn translate(range: Range<u64>, from: u64, to: u64) -> Range<u64> {
+    (range.start - from).saturating_add(to)..(range.end - from).saturating_add(to)
+}
+
+/// Numbers the `ranges` are mapped to by a single map
+pub fn forward(map: &Map, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+    let mut mapped = Vec::new();
+    let mut pending = ranges.to_vec();
+
+    for &(destination, source, len) in map {
+        let window = source..source.saturating_add(len);
+        let mut outside = Vec::new();
+        for range in pending {
+            let (inside, rest) = split(&range, &window);
+            if !inside.is_empty() {
+                mapped.push(translate(inside, source, destination));
+            }
+            outside.extend(rest.into_iter().filter(|r| !r.is_empty()));
+        }
+        pending = outside;
+    }
+
+    mapped.extend(pending);
+    merge(mapped)
+}
+
+/// Numbers that a single map sends into `ranges`, the inverse of [`forward`]
+pub fn backward(map: &Map, ranges: &[Range<u64>]) -> Vec<Range<u64>> {
+    let mut sources = Vec::new();
+
+    for &(destination, source, len) in map {
+        let window = destination..destination.saturating_add(len);
+        for range in ranges {
+            let (inside, _) = split(range, &window);
+            if !inside.is_empty() {
+                sources.push(translate(inside, destination, source));
+            }
+        }
+    }
+
+    // Numbers outside every source range keep their value
+    let mut unmapped = ranges.to_vec();
+    for &(_, source, len) in map {
+        let window = source..source.saturating_add(len);
+        unmapped = unmapped
+            .iter()
+            .flat_map(|range| split(range, &window).1)
+            .filter(|r| !r.is_empty())
+            .collect();
+    }
+
+    sources.extend(unmapped);
+    merge(sources)
+}
+
+#[cfg(test)]
+#[allow(clippy::single_range_in_vec_init)]
+mod tests {
+    use super::*;
+
+    const SEED_TO_SOIL: &Map = &[(50, 98, 2), (52, 50, 48)];
+
+    #[test]
+    fn merge_ranges() {
+        let ranges = vec![10..20, 0..5, 5..7, 15..25, 30..30];
+        assert_eq!(merge(ranges), vec![0..7, 10..25]);
+    }
+
+    #[test]
+    fn intersect_ranges() {
+        assert_eq!(intersect(&[0..10, 20..30], &[5..25]), vec![5..10, 20..25]);
+        assert_eq!(intersect(&[0..10], &[10..20]), vec![]);
+    }
+
+    #[test]
+    fn forward_splits_at_boundaries() {
+        assert_eq!(forward(SEED_TO_SOIL, &[79..93]), vec![81..95]);
+        assert_eq!(forward(SEED_TO_SOIL, &[45..55]), vec![45..50, 52..57]);
+        // 96 and 97 go to 98 and 99, right before the unmapped 100 and 101
+        assert_eq!(forward(SEED_TO_SOIL, &[96..102]), vec![50..52, 98..102]);
+    }
+
+    #[test]
+    fn backward_inverts_forward() {
+        assert_eq!(backward(SEED_TO_SOIL, &[81..95]), vec![79..93]);
+        // 50 and 51 come from 98 and 99, 52 and 53 from 50 and 51, while the
+        // seeds 50 to 53 themselves are mapped elsewhere
+        assert_eq!(backward(SEED_TO_SOIL, &[50..54]), vec![50..52, 98..100]);
+        assert_eq!(backward(SEED_TO_SOIL, &[0..10]), vec![0..10]);
+        assert_eq!(backward(SEED_TO_SOIL, &[100..110]), vec![100..110]);
+
+        for seed in 0..110 {
+            let soil = forward(SEED_TO_SOIL, &[seed..seed + 1]);
+            let seeds = backward(SEED_TO_SOIL, &soil);
+            assert_eq!(intersect(&seeds, &[seed..seed + 1]), vec![seed..seed + 1]);
+        }
+    }
+}
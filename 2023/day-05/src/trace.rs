@@ -0,0 +1,60 @@
+use core::fmt;
+use std::ops::Range;
+
+/// Value of a single seed in every category, from the seed to its location
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trace {
+    pub categories: Vec<String>,
+    pub values: Vec<u64>,
+}
+
+impl Trace {
+    pub fn seed(&self) -> u64 {
+        self.values[0]
+    }
+
+    pub fn location(&self) -> u64 {
+        self.values[self.values.len() - 1]
+    }
+}
+
+/// Lists one category per line, e.g. `soil        81`
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.categories.iter().map(String::len).max().unwrap_or(0);
+        for (category, value) in self.categories.iter().zip(&self.values) {
+            writeln!(f, "{category:<width$} {value}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Ranges of every category that end up in a range of locations, from the
+/// seeds to the locations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TraceBack {
+    pub categories: Vec<String>,
+    pub ranges: Vec<Vec<Range<u64>>>,
+}
+
+impl TraceBack {
+    pub fn seeds(&self) -> &[Range<u64>] {
+        &self.ranges[0]
+    }
+}
+
+/// Lists one category per line, e.g. `humidity    46..47, 56..57`
+impl fmt::Display for TraceBack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.categories.iter().map(String::len).max().unwrap_or(0);
+        for (category, ranges) in self.categories.iter().zip(&self.ranges) {
+            let ranges = ranges
+                .iter()
+                .map(|r| format!("{r:?}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            writeln!(f, "{category:<width$} {ranges}")?;
+        }
+        Ok(())
+    }
+}
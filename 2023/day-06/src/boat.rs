@@ -0,0 +1,193 @@
+use std::fmt;
+
+/// Distance too long to count, even in `u128`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the distance travelled does not fit in 128 bits")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// How a boat gains speed while the button is held and loses it afterwards
+///
+/// The boat of the puzzle is [`Boat::default`], gaining 1 mm/ms for every
+/// millisecond held, without a top speed or friction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Boat {
+    /// Speed gained for each millisecond holding the button, in mm/ms
+    pub acceleration: u64,
+    /// Speed the boat can't go over, in mm/ms
+    pub top_speed: Option<u64>,
+    /// Speed lost for each millisecond travelled, in mm/ms
+    pub friction: u64,
+}
+
+impl Default for Boat {
+    fn default() -> Self {
+        Self {
+            acceleration: 1,
+            top_speed: None,
+            friction: 0,
+        }
+    }
+}
+
+impl Boat {
+    /// Speed when the button is released after `time_holding`
+    pub fn speed(&self, time_holding: u64) -> u128 {
+        let speed = self.acceleration as u128 * time_holding as u128;
+        match self.top_speed {
+            Some(top_speed) => speed.min(top_speed as u128),
+            None => speed,
+        }
+    }
+
+    /// Distance travelled in a race lasting `race_time`, holding the button
+    /// for `time_holding`
+    ///
+    /// With friction the boat moves at `v`, `v - f`, `v - 2f` and so on for
+    /// the `m` milliseconds it still has speed, adding up to
+    /// `m * v - f * m * (m - 1) / 2`.
+    pub fn distance(&self, race_time: u64, time_holding: u64) -> Result<u128, Overflow> {
+        let speed = self.speed(time_holding);
+        let travelling = race_time.saturating_sub(time_holding) as u128;
+        if self.friction == 0 {
+            return speed.checked_mul(travelling).ok_or(Overflow);
+        }
+
+        let friction = self.friction as u128;
+        let moving = travelling.min(speed.div_ceil(friction));
+        let lost = friction
+            .checked_mul(moving)
+            .and_then(|lost| lost.checked_mul(moving.saturating_sub(1)))
+            .ok_or(Overflow)?;
+        let distance = moving.checked_mul(speed).ok_or(Overflow)?;
+        Ok(distance - lost / 2)
+    }
+
+    /// Same as [`Boat::distance`], moving the boat one millisecond at a time
+    pub fn simulate(&self, race_time: u64, time_holding: u64) -> Result<u128, Overflow> {
+        let top_speed = self.top_speed.map_or(u128::MAX, u128::from);
+        let mut speed = 0u128;
+        let mut distance = 0u128;
+        for ms in 0..race_time {
+            if ms < time_holding {
+                speed = (speed + self.acceleration as u128).min(top_speed);
+            } else {
+                distance = distance.checked_add(speed).ok_or(Overflow)?;
+                speed = speed.saturating_sub(self.friction as u128);
+            }
+        }
+        Ok(distance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Boat, Overflow};
+    use crate::Race;
+    use proptest::{option, prelude::*};
+
+    #[test]
+    fn puzzle_boat() {
+        let boat = Boat::default();
+        let distances = (0..=7)
+            .map(|h| boat.distance(7, h).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(distances, vec![0, 6, 10, 12, 12, 10, 6, 0]);
+    }
+
+    #[test]
+    fn overflow() {
+        let boat = Boat {
+            acceleration: u64::MAX,
+            ..Boat::default()
+        };
+        // Released at almost 2^128 mm/ms, too fast to travel for 2 ms
+        assert_eq!(
+            boat.distance(u64::MAX, u64::MAX - 1),
+            Ok(u64::MAX as u128 * (u64::MAX - 1) as u128)
+        );
+        assert_eq!(boat.distance(u64::MAX, u64::MAX - 2), Err(Overflow));
+        assert_eq!(
+            Race {
+                time: u64::MAX,
+                distance: 0
+            }
+            .count_winning_times_with(&boat),
+            Err(Overflow)
+        );
+
+        let slowing = Boat {
+            friction: 1,
+            ..boat
+        };
+        assert_eq!(slowing.distance(u64::MAX, u64::MAX / 2), Err(Overflow));
+    }
+
+    #[test]
+    fn top_speed_and_friction() {
+        let boat = Boat {
+            acceleration: 3,
+            top_speed: Some(7),
+            friction: 2,
+        };
+        // Released at 7 mm/ms, moving at 7, 5, 3 and 1 before stopping
+        assert_eq!(boat.distance(10, 3), Ok(16));
+        assert_eq!(boat.distance(5, 3), Ok(12));
+        assert_eq!(boat.simulate(10, 3), Ok(16));
+        assert_eq!(boat.simulate(5, 3), Ok(12));
+    }
+
+    fn boats() -> impl Strategy<Value = Boat> {
+        (0u64..6, option::of(0u64..40), 0u64..6).prop_map(|(acceleration, top_speed, friction)| {
+            Boat {
+                acceleration,
+                top_speed,
+                friction,
+            }
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn distance_matches_simulation(
+            boat in boats(),
+            race_time in 0u64..80,
+            time_holding in 0u64..90,
+        ) {
+            prop_assert_eq!(
+                boat.distance(race_time, time_holding),
+                boat.simulate(race_time, time_holding)
+            );
+        }
+
+        #[test]
+        fn closed_form_matches_simulation(time in 0u64..300, distance in 0u64..25_000) {
+            let race = Race { time, distance };
+            let boat = Boat::default();
+            let simulated = (0..=time)
+                .filter(|&h| boat.simulate(time, h).unwrap() > distance as u128)
+                .count() as u64;
+            prop_assert_eq!(race.count_winning_times(), simulated);
+            prop_assert_eq!(race.count_winning_times_with(&boat), Ok(simulated));
+        }
+
+        #[test]
+        fn winning_times_match_simulation(
+            boat in boats(),
+            time in 0u64..80,
+            distance in 0u64..1_500,
+        ) {
+            let race = Race { time, distance };
+            let simulated = (0..=time)
+                .filter(|&h| boat.simulate(time, h).unwrap() > distance as u128)
+                .count() as u64;
+            prop_assert_eq!(race.count_winning_times_with(&boat), Ok(simulated));
+        }
+    }
+}
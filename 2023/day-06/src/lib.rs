@@ -0,0 +1,350 @@
+use common::{ParseError, Solution};
+use itertools::Itertools;
+use std::{convert::Infallible, fmt::Display, ops::Range, str::FromStr};
+
+pub mod boat;
+
+use boat::{Boat, Overflow};
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    /// The races of part one and the single race of part two, read from the
+    /// same sheet ignoring the spaces between the numbers
+    type Input = (Vec<Race>, Race);
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        let races = Race::from_str(input)?;
+        let race = input.parse::<Race>()?;
+        Ok((races, race))
+    }
+
+    fn part_one((races, _): &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(get_part_one(races))
+    }
+
+    fn part_two((_, race): &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(race.count_winning_times())
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Race {
+    time: u64,
+    distance: u64,
+}
+
+/// Numbers of the sheet line starting with `label`
+fn get_numbers<'a>(s: &'a str, line: Option<&'a str>, label: &str) -> Result<&'a str, ParseError> {
+    let line = line.ok_or_else(|| ParseError::after(s, s.trim_end(), format!("`{label}`")))?;
+    line.strip_prefix(label).ok_or_else(|| {
+        let word = line.split_whitespace().next().unwrap_or(line);
+        ParseError::at(s, word, format!("`{label}`"))
+    })
+}
+
+impl Race {
+    fn from_str(s: &str) -> Result<Vec<Self>, ParseError> {
+        let mut iter = s.lines();
+        let mut parse_line = |label| {
+            let numbers = get_numbers(s, iter.next(), label)?;
+            numbers
+                .split_whitespace()
+                .map(|w| {
+                    w.parse::<u64>()
+                        .map_err(|_| ParseError::at(s, w, "a number"))
+                })
+                .collect::<Result<Vec<_>, _>>()
+                .map(|parsed| (numbers, parsed))
+        };
+        let (_, times) = parse_line("Time:")?;
+        let (distances_str, distances) = parse_line("Distance:")?;
+        if times.len() != distances.len() {
+            return Err(ParseError::at(
+                s,
+                distances_str,
+                format!("{} distances, one for each time", times.len()),
+            ));
+        }
+
+        Ok(times
+            .into_iter()
+            .zip(distances)
+            .map(|(time, distance)| Self { time, distance })
+            .collect_vec())
+    }
+
+    /// Distance travelled holding the button for `time_holding`, computed in
+    /// `u128` so it can't overflow for any race
+    fn get_distance(&self, time_holding: u64) -> u128 {
+        time_holding as u128 * self.time.saturating_sub(time_holding) as u128
+    }
+
+    /// Hold times that beat the record, the ones where `h * (T - h) > D`
+    ///
+    /// The distances are symmetric around `T / 2`, so the winning times go
+    /// from the smallest root of `h² - T * h + D = 0` to its mirror.
+    pub fn get_winning_times(&self) -> Range<u64> {
+        let (time, distance) = (self.time as u128, self.distance as u128);
+        if self.get_distance(self.time / 2) <= distance {
+            return 0..0;
+        }
+
+        // The square root is floored, so the root may be off by one, which
+        // the exact distances then correct
+        let mut first = (time - (time * time - 4 * distance).isqrt()) / 2;
+        while first > 0 && self.get_distance(first as u64 - 1) > distance {
+            first -= 1;
+        }
+        while self.get_distance(first as u64) <= distance {
+            first += 1;
+        }
+
+        first as u64..(time - first + 1) as u64
+    }
+
+    pub fn count_winning_times(&self) -> u64 {
+        let times = self.get_winning_times();
+        times.end - times.start
+    }
+
+    /// Number of hold times that beat the record racing with `boat`
+    ///
+    /// Other boats may not have symmetric distances, but holding longer still
+    /// goes further until the best hold time and less far after it. Both sides
+    /// are monotone, so the best hold time and the first and last ones beating
+    /// the record are bisected.
+    pub fn count_winning_times_with(&self, boat: &Boat) -> Result<u64, Overflow> {
+        let distance = |time_holding| boat.distance(self.time, time_holding);
+        let record = self.distance as u128;
+        let best = bisect(0..self.time, |h| Ok(distance(h + 1)? <= distance(h)?))?;
+        if distance(best)? <= record {
+            return Ok(0);
+        }
+
+        let first = bisect(0..best, |h| Ok(distance(h)? > record))?;
+        let after_last = bisect(best..self.time, |h| Ok(distance(h + 1)? <= record))?;
+        Ok(after_last - first + 1)
+    }
+}
+
+/// First time of `times` where `done` holds, or the end of `times` if it never
+/// does, `done` holding for every time after the first one it holds for
+fn bisect(
+    times: Range<u64>,
+    mut done: impl FnMut(u64) -> Result<bool, Overflow>,
+) -> Result<u64, Overflow> {
+    let (mut start, mut end) = (times.start, times.end);
+    while start < end {
+        let middle = start + (end - start) / 2;
+        if done(middle)? {
+            end = middle;
+        } else {
+            start = middle + 1;
+        }
+    }
+    Ok(start)
+}
+
+impl FromStr for Race {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let mut parse_line = |label| {
+            let numbers = get_numbers(s, lines.next(), label)?;
+            numbers
+                .split_whitespace()
+                .join("")
+                .parse::<u64>()
+                .map_err(|_| ParseError::at(s, numbers.trim(), "a number"))
+        };
+        let time = parse_line("Time:")?;
+        let distance = parse_line("Distance:")?;
+        Ok(Self { time, distance })
+    }
+}
+
+fn get_part_one(races: &[Race]) -> u64 {
+    races
+        .iter()
+        .map(|race| race.count_winning_times())
+        .reduce(|acc, cur| acc * cur)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::{boat::Boat, get_part_one, Race};
+    use common::ParseError;
+
+    fn get_input() -> String {
+        fs::read_to_string("./example.txt").unwrap()
+    }
+
+    fn get_all_distances(race: &Race) -> Vec<u128> {
+        (0..=race.time)
+            .map(|time_holding| race.get_distance(time_holding))
+            .collect()
+    }
+
+    #[test]
+    fn parse() {
+        let races = Race::from_str(&get_input()).unwrap();
+        assert_eq!(
+            races,
+            vec![
+                Race {
+                    time: 7,
+                    distance: 9
+                },
+                Race {
+                    time: 15,
+                    distance: 40
+                },
+                Race {
+                    time: 30,
+                    distance: 200
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = Race::from_str("Time: 7 15\nDistance: 9 x0").unwrap_err();
+        assert_eq!(err, ParseError::new(1, 12..14, "a number"));
+
+        let err = Race::from_str("Time: 7 15\nDistance: 9").unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(1, 9..11, "2 distances, one for each time")
+        );
+
+        let err = "Time: 7 15\nSpeed: 9 40".parse::<Race>().unwrap_err();
+        assert_eq!(err, ParseError::new(1, 0..6, "`Distance:`"));
+
+        let err = "Time: 7 15".parse::<Race>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 10..11, "`Distance:`"));
+    }
+
+    #[test]
+    fn distance() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        assert_eq!(race.get_distance(0), 0);
+        assert_eq!(race.get_distance(1), 6);
+        assert_eq!(race.get_distance(2), 10);
+        assert_eq!(race.get_distance(3), 12);
+        assert_eq!(race.get_distance(4), 12);
+        assert_eq!(race.get_distance(5), 10);
+        assert_eq!(race.get_distance(6), 6);
+        assert_eq!(race.get_distance(7), 0);
+    }
+
+    #[test]
+    fn all_disances() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        assert_eq!(get_all_distances(&race), vec![0, 6, 10, 12, 12, 10, 6, 0])
+    }
+
+    #[test]
+    fn winning_times() {
+        let race = Race {
+            time: 7,
+            distance: 9,
+        };
+        assert_eq!(race.get_winning_times(), 2..6)
+    }
+
+    #[test]
+    fn winning_times_match_all_distances() {
+        for time in 0..40 {
+            for distance in 0..=time * time / 4 + 1 {
+                let race = Race { time, distance };
+                let winning = get_all_distances(&race)
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, &d)| d > distance as u128)
+                    .map(|(i, _)| i as u64)
+                    .collect::<Vec<_>>();
+                let expected = match (winning.first(), winning.last()) {
+                    (Some(&first), Some(&last)) => first..last + 1,
+                    _ => 0..0,
+                };
+                assert_eq!(race.get_winning_times(), expected, "{race:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn winning_times_without_overflow() {
+        let race = Race {
+            time: u64::MAX,
+            distance: u64::MAX - 2,
+        };
+        let times = race.get_winning_times();
+        assert_eq!(times, 1..u64::MAX);
+
+        let race = Race {
+            time: u64::MAX,
+            distance: u64::MAX,
+        };
+        let times = race.get_winning_times();
+        assert_eq!(times.start + (times.end - 1), u64::MAX);
+        assert!(race.get_distance(times.start) > u64::MAX as u128);
+        assert!(race.get_distance(times.start - 1) <= u64::MAX as u128);
+    }
+
+    #[test]
+    fn winning_times_with_boat() {
+        let race = Race {
+            time: 30,
+            distance: 200,
+        };
+        assert_eq!(race.count_winning_times_with(&Boat::default()), Ok(9));
+
+        let fast = Boat {
+            acceleration: 2,
+            ..Boat::default()
+        };
+        let capped = Boat {
+            top_speed: Some(10),
+            ..Boat::default()
+        };
+        assert_eq!(race.count_winning_times_with(&fast), Ok(23));
+        assert_eq!(race.count_winning_times_with(&capped), Ok(0));
+    }
+
+    #[test]
+    fn part_one() {
+        let races = Race::from_str(&get_input()).unwrap();
+        let margins = get_part_one(&races);
+        assert_eq!(margins, 288);
+    }
+
+    #[test]
+    fn parse_single_race() {
+        let race = get_input().parse::<Race>().unwrap();
+        assert_eq!(
+            race,
+            Race {
+                time: 71530,
+                distance: 940200
+            }
+        );
+    }
+
+    #[test]
+    fn part_two() {
+        let race = get_input().parse::<Race>().unwrap();
+        assert_eq!(race.count_winning_times(), 71503);
+    }
+}
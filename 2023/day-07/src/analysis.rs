@@ -0,0 +1,278 @@
+use crate::{Card, Hand, HandType};
+use core::fmt;
+use std::cmp::Ordering;
+
+/// Wild card of a hand and the card it is played as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Substitution {
+    /// Position of the card in the hand, starting at 1
+    pub position: usize,
+    pub card: Card,
+    pub played_as: Card,
+}
+
+/// Type of a hand and how its wild cards were used to reach it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Analysis {
+    pub hand_type: HandType,
+    pub substitutions: Vec<Substitution>,
+}
+
+/// Lists the substitutions after the type, e.g. `four of a kind (J at 4 as 5)`
+impl fmt::Display for Analysis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.hand_type)?;
+        if self.substitutions.is_empty() {
+            return Ok(());
+        }
+        let substitutions = self
+            .substitutions
+            .iter()
+            .map(|s| format!("{} at {} as {}", s.card, s.position, s.played_as))
+            .collect::<Vec<_>>();
+        write!(f, " ({})", substitutions.join(", "))
+    }
+}
+
+/// What decided the order of two hands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TieBreak {
+    /// The hands have different types
+    Type { ours: HandType, theirs: HandType },
+    /// Same type, decided by the first card of different strength
+    Card {
+        hand_type: HandType,
+        position: usize,
+        ours: Card,
+        theirs: Card,
+    },
+    /// Same type and cards just as strong
+    Equal { hand_type: HandType },
+}
+
+/// Card that is not in the deck of the rules hands are compared with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotInDeck(pub Card);
+
+impl fmt::Display for NotInDeck {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not a card of the deck", self.0)
+    }
+}
+
+impl std::error::Error for NotInDeck {}
+
+/// Order of a hand against another one and the reason for it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation {
+    pub ordering: Ordering,
+    pub tie_break: TieBreak,
+}
+
+/// Explains from the point of view of the first hand, e.g. `both are one
+/// pair, card 1 K beats Q`
+impl fmt::Display for Explanation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verb = match self.ordering {
+            Ordering::Greater => "beats",
+            Ordering::Less => "loses to",
+            Ordering::Equal => "ties with",
+        };
+        match &self.tie_break {
+            TieBreak::Type { ours, theirs } => write!(f, "{ours} {verb} {theirs}"),
+            TieBreak::Card {
+                hand_type,
+                position,
+                ours,
+                theirs,
+            } => write!(
+                f,
+                "both are {hand_type}, card {position} {ours} {verb} {theirs}"
+            ),
+            TieBreak::Equal { hand_type } => {
+                write!(f, "both are {hand_type} with cards just as strong")
+            }
+        }
+    }
+}
+
+impl Hand {
+    /// Type of the hand along with the card each wild card is played as
+    pub fn analyze(&self) -> Analysis {
+        let substitute = self.wild_substitute();
+        let substitutions = self
+            .cards
+            .iter()
+            .enumerate()
+            .filter(|(_, &card)| self.rules.is_wild(card))
+            .map(|(i, &card)| Substitution {
+                position: i + 1,
+                card,
+                played_as: substitute,
+            })
+            .collect();
+        Analysis {
+            hand_type: self.get_type(),
+            substitutions,
+        }
+    }
+
+    /// Why this hand ranks above, below or along with `other`, both being
+    /// ranked with the rules of this hand, which fails if one of the hands has
+    /// a card that is not in their deck
+    pub fn explain(&self, other: &Hand) -> Result<Explanation, NotInDeck> {
+        if let Some(&card) = self
+            .cards
+            .iter()
+            .chain(&other.cards)
+            .find(|&&card| !self.rules.contains(card))
+        {
+            return Err(NotInDeck(card));
+        }
+
+        let other = other.clone().with_rules(self.rules.clone());
+        let (ours, theirs) = (self.get_type(), other.get_type());
+        if ours != theirs {
+            return Ok(Explanation {
+                ordering: ours.cmp(&theirs),
+                tie_break: TieBreak::Type { ours, theirs },
+            });
+        }
+
+        let hand_type = ours;
+        let strength = |card| self.rules.strength(card);
+        let explanation = self
+            .cards
+            .iter()
+            .zip(&other.cards)
+            .enumerate()
+            .find(|(_, (&ours, &theirs))| strength(ours) != strength(theirs))
+            .map_or(
+                Explanation {
+                    ordering: Ordering::Equal,
+                    tie_break: TieBreak::Equal {
+                        hand_type: hand_type.clone(),
+                    },
+                },
+                |(i, (&ours, &theirs))| Explanation {
+                    ordering: strength(ours).cmp(&strength(theirs)),
+                    tie_break: TieBreak::Card {
+                        hand_type,
+                        position: i + 1,
+                        ours,
+                        theirs,
+                    },
+                },
+            );
+        Ok(explanation)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{NotInDeck, Substitution, TieBreak};
+    use crate::{parse_hands, Card, Hand, Rules};
+    use std::cmp::Ordering;
+
+    fn hand(cards: &str, rules: Rules) -> Hand {
+        format!("{cards} 1")
+            .parse::<Hand>()
+            .unwrap()
+            .with_rules(rules)
+    }
+
+    #[test]
+    fn analyze() {
+        let analysis = hand("KTJJT", Rules::JOKERS).analyze();
+        assert_eq!(analysis.hand_type.counts(), [4, 1]);
+        assert_eq!(
+            analysis.substitutions,
+            vec![
+                Substitution {
+                    position: 3,
+                    card: Card::new('J'),
+                    played_as: Card::new('T')
+                },
+                Substitution {
+                    position: 4,
+                    card: Card::new('J'),
+                    played_as: Card::new('T')
+                }
+            ]
+        );
+        assert_eq!(
+            analysis.to_string(),
+            "four of a kind (J at 3 as T, J at 4 as T)"
+        );
+
+        let analysis = hand("KTJJT", Rules::STANDARD).analyze();
+        assert_eq!(analysis.to_string(), "two pair");
+
+        // Ties between the most common cards go to the strongest one
+        let analysis = hand("2KK2J", Rules::JOKERS).analyze();
+        assert_eq!(analysis.to_string(), "full house (J at 5 as K)");
+
+        let analysis = hand("JJJJJ", Rules::JOKERS).analyze();
+        assert_eq!(
+            analysis.to_string(),
+            "five of a kind (J at 1 as A, J at 2 as A, J at 3 as A, J at 4 as A, J at 5 as A)"
+        );
+    }
+
+    #[test]
+    fn explain() {
+        let explanation = hand("QQQJA", Rules::JOKERS)
+            .explain(&hand("T55J5", Rules::JOKERS))
+            .unwrap();
+        assert_eq!(explanation.ordering, Ordering::Greater);
+        assert_eq!(
+            explanation.tie_break,
+            TieBreak::Card {
+                hand_type: hand("QQQQA", Rules::STANDARD).get_type(),
+                position: 1,
+                ours: Card::new('Q'),
+                theirs: Card::new('T')
+            }
+        );
+        assert_eq!(
+            explanation.to_string(),
+            "both are four of a kind, card 1 Q beats T"
+        );
+
+        let explanation = hand("KK677", Rules::JOKERS)
+            .explain(&hand("KTJJT", Rules::STANDARD))
+            .unwrap();
+        assert_eq!(explanation.to_string(), "two pair loses to four of a kind");
+
+        let explanation = hand("JKKK2", Rules::JOKERS)
+            .explain(&hand("QQQQ2", Rules::JOKERS))
+            .unwrap();
+        assert_eq!(
+            explanation.to_string(),
+            "both are four of a kind, card 1 J loses to Q"
+        );
+
+        let explanation = hand("AAAAA", Rules::JOKERS)
+            .explain(&hand("AAAAA", Rules::JOKERS))
+            .unwrap();
+        assert_eq!(
+            explanation.to_string(),
+            "both are five of a kind with cards just as strong"
+        );
+    }
+
+    #[test]
+    fn explain_outside_the_deck() {
+        let rules = "cards = 123456\nhand size = 3".parse::<Rules>().unwrap();
+        let small = hand("AAAAA", Rules::STANDARD).with_rules(rules.clone());
+        let err = small.explain(&hand("KKKKK", Rules::STANDARD)).unwrap_err();
+        assert_eq!(err, NotInDeck(Card::new('A')));
+        assert_eq!(err.to_string(), "A is not a card of the deck");
+
+        let hand = parse_hands("123 1", &rules).unwrap().remove(0);
+        let err = hand
+            .explain(&self::hand("KKKKK", Rules::STANDARD))
+            .unwrap_err();
+        assert_eq!(err, NotInDeck(Card::new('K')));
+    }
+}
@@ -0,0 +1,123 @@
+use crate::Card;
+use core::fmt;
+use std::collections::HashMap;
+
+/// Type of a hand, given by how many times each of its cards shows up, from
+/// the most common card to the least common
+///
+/// Types compare those counts in order, so the most common card decides
+/// first, then the second most common and so on. For hands of five cards this
+/// is the usual order from high card to five of a kind, and it ranks hands of
+/// any other size the same way.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HandType(Vec<usize>);
+
+impl HandType {
+    /// Type of a hand holding `cards`
+    pub fn classify(cards: &[Card]) -> Self {
+        let mut counts = HashMap::new();
+        for card in cards {
+            *counts.entry(card).or_insert(0) += 1;
+        }
+        let mut counts = counts.into_values().collect::<Vec<usize>>();
+        counts.sort_unstable_by(|a, b| b.cmp(a));
+        HandType(counts)
+    }
+
+    /// How many times each card of the hand shows up, the most common first
+    pub fn counts(&self) -> &[usize] {
+        &self.0
+    }
+}
+
+/// Name of a number as written in the hand types, e.g. `five`
+pub(crate) fn number_name(n: usize) -> String {
+    const NAMES: [&str; 11] = [
+        "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+    ];
+    NAMES
+        .get(n)
+        .map_or_else(|| n.to_string(), |name| name.to_string())
+}
+
+/// Names the groups of equal cards, e.g. `full house` or, in hands bigger
+/// than five cards, `four of a kind and a pair`
+impl fmt::Display for HandType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let groups = self
+            .0
+            .iter()
+            .filter(|&&count| count > 1)
+            .collect::<Vec<_>>();
+        match groups[..] {
+            [] => write!(f, "high card"),
+            [2] => write!(f, "one pair"),
+            [3, 2] => write!(f, "full house"),
+            [n] => write!(f, "{} of a kind", number_name(*n)),
+            _ if groups.iter().all(|&&count| count == 2) => {
+                write!(f, "{} pair", number_name(groups.len()))
+            }
+            _ => {
+                let names = groups
+                    .iter()
+                    .map(|&&count| match count {
+                        2 => "a pair".to_string(),
+                        n => format!("{} of a kind", number_name(n)),
+                    })
+                    .collect::<Vec<_>>();
+                write!(f, "{}", names.join(" and "))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HandType;
+    use crate::Card;
+
+    fn classify(cards: &str) -> HandType {
+        let cards = cards.chars().map(Card::new).collect::<Vec<_>>();
+        HandType::classify(&cards)
+    }
+
+    #[test]
+    fn classify_five_cards() {
+        assert_eq!(classify("AAAAA").counts(), [5]);
+        assert_eq!(classify("AA8AA").counts(), [4, 1]);
+        assert_eq!(classify("23332").counts(), [3, 2]);
+        assert_eq!(classify("TTT98").counts(), [3, 1, 1]);
+        assert_eq!(classify("23432").counts(), [2, 2, 1]);
+        assert_eq!(classify("A23A4").counts(), [2, 1, 1, 1]);
+        assert_eq!(classify("23456").counts(), [1, 1, 1, 1, 1]);
+    }
+
+    #[test]
+    fn classify_any_size() {
+        assert_eq!(classify("AAAAAAA").counts(), [7]);
+        assert!(classify("AAAA22") > classify("AAA222"));
+        assert!(classify("AAA222") > classify("AAA234"));
+        assert!(classify("AA22") > classify("AAK2"));
+        assert!(classify("AKQ") < classify("AAK"));
+
+        // More of a card than fits in a byte
+        let many = "A".repeat(300) + "K";
+        assert_eq!(classify(&many).counts(), [300, 1]);
+        assert_eq!(classify(&many).to_string(), "300 of a kind");
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(classify("AAAAA").to_string(), "five of a kind");
+        assert_eq!(classify("23332").to_string(), "full house");
+        assert_eq!(classify("23432").to_string(), "two pair");
+        assert_eq!(classify("23456").to_string(), "high card");
+        assert_eq!(classify("AAAAAA").to_string(), "six of a kind");
+        assert_eq!(classify("AA22KK").to_string(), "three pair");
+        assert_eq!(classify("AAAA22").to_string(), "four of a kind and a pair");
+        assert_eq!(
+            classify("AAA222").to_string(),
+            "three of a kind and three of a kind"
+        );
+    }
+}
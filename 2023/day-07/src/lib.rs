@@ -0,0 +1,440 @@
+use common::{ParseError, Solution};
+use core::fmt;
+use std::{cmp::Ordering, convert::Infallible, fmt::Display, str::FromStr};
+
+pub mod analysis;
+pub mod hand_type;
+pub mod rules;
+
+pub use hand_type::HandType;
+pub use rules::Rules;
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Hand>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse_hands(input, &Rules::STANDARD)
+    }
+
+    fn part_one(hands: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(get_total_winnings_with(hands, &Rules::STANDARD))
+    }
+
+    fn part_two(hands: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(get_total_winnings_with(hands, &Rules::JOKERS))
+    }
+}
+
+/// Card of a deck, told apart by its label
+///
+/// Cards are only ranked by the [`Rules`] of a game, their own order just
+/// follows the labels.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Card(char);
+
+impl Card {
+    pub const fn new(label: char) -> Self {
+        Card(label)
+    }
+
+    pub fn label(self) -> char {
+        self.0
+    }
+}
+
+impl fmt::Display for Card {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Hand of cards and its bid, ranked with the rules of its game
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Hand {
+    points: u16,
+    cards: Vec<Card>,
+    rules: Rules,
+}
+
+impl Hand {
+    /// Parses a line of cards and a bid, the cards coming from the deck of
+    /// `rules`
+    pub fn parse_with(s: &str, rules: &Rules) -> Result<Self, ParseError> {
+        let size = format!("{} cards", hand_type::number_name(rules.hand_size()));
+        let mut words = s.split_whitespace();
+        let cards_str = words.next().ok_or_else(|| ParseError::at(s, s, &size))?;
+        let cards = cards_str
+            .char_indices()
+            .map(|(i, label)| {
+                let card = Card::new(label);
+                match rules.contains(card) {
+                    true => Ok(card),
+                    false => {
+                        let card = &cards_str[i..i + label.len_utf8()];
+                        Err(ParseError::at(s, card, rules.describe_card()))
+                    }
+                }
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if cards.len() != rules.hand_size() {
+            return Err(ParseError::at(s, cards_str, size));
+        }
+        let points = words
+            .next()
+            .ok_or_else(|| ParseError::after(s, cards_str, "a bid"))?;
+        let points = points
+            .parse::<u16>()
+            .map_err(|_| ParseError::at(s, points, "a bid"))?;
+        Ok(Self {
+            points,
+            cards,
+            rules: rules.clone(),
+        })
+    }
+
+    /// Same hand played with other rules, which must have every card of the
+    /// hand in their deck
+    pub fn with_rules(self, rules: Rules) -> Self {
+        Self { rules, ..self }
+    }
+
+    pub fn rules(&self) -> &Rules {
+        &self.rules
+    }
+
+    pub fn bid(&self) -> u16 {
+        self.points
+    }
+
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    /// Card the wild cards stand for: the most common of the other cards,
+    /// the strongest of them on ties, or the strongest card of the deck when
+    /// every card is wild
+    pub fn wild_substitute(&self) -> Card {
+        let others = self
+            .cards
+            .iter()
+            .filter(|&&card| !self.rules.is_wild(card))
+            .copied()
+            .collect::<Vec<_>>();
+        let count = |card| others.iter().filter(|&&c| c == card).count();
+        others
+            .iter()
+            .copied()
+            .max_by_key(|&card| (count(card), self.rules.strength(card)))
+            .or_else(|| self.rules.cards().last().copied())
+            .unwrap_or(self.cards[0])
+    }
+
+    /// Cards of the hand once the wild cards are replaced
+    fn played_cards(&self) -> Vec<Card> {
+        let substitute = self.wild_substitute();
+        self.cards
+            .iter()
+            .map(|&card| match self.rules.is_wild(card) {
+                true => substitute,
+                false => card,
+            })
+            .collect()
+    }
+
+    /// Type of the hand, wild cards joining the most common of the other
+    /// cards
+    pub fn get_type(&self) -> HandType {
+        HandType::classify(&self.played_cards())
+    }
+}
+
+impl fmt::Display for Hand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.cards.iter().try_for_each(|card| write!(f, "{card}"))
+    }
+}
+
+/// Parses a hand of the standard game
+impl FromStr for Hand {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Hand::parse_with(s, &Rules::STANDARD)
+    }
+}
+
+/// Parses a hand on each line of `input`, played with `rules`
+pub fn parse_hands(input: &str, rules: &Rules) -> Result<Vec<Hand>, ParseError> {
+    input
+        .lines()
+        .map(|line| Hand::parse_with(line, rules).map_err(|err| err.within(input, line)))
+        .collect()
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Orders hands by type and then by the strength of their cards, both given
+/// by the rules of each hand
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let strengths = |hand: &Self| {
+            hand.cards
+                .iter()
+                .map(|&card| hand.rules.strength(card))
+                .collect::<Vec<_>>()
+        };
+        self.get_type()
+            .cmp(&other.get_type())
+            .then_with(|| strengths(self).cmp(&strengths(other)))
+            .then_with(|| self.rules.cmp(&other.rules))
+    }
+}
+
+/// Winnings of `hands` played with `rules`, whatever rules they had before
+pub fn get_total_winnings_with(hands: &[Hand], rules: &Rules) -> u64 {
+    let mut hands = hands
+        .iter()
+        .map(|hand| hand.clone().with_rules(rules.clone()))
+        .collect::<Vec<_>>();
+    hands.sort();
+    get_total_winnings(&hands)
+}
+
+/// Winnings of `hands`, which must be sorted from the weakest to the strongest
+pub fn get_total_winnings(hands: &[Hand]) -> u64 {
+    hands.iter().enumerate().fold(0, |acc, (i, card)| {
+        acc + (i as u64 + 1) * card.points as u64
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{Card, Hand};
+    use itertools::Itertools;
+    use std::fs;
+
+    fn get_input() -> String {
+        fs::read_to_string("./example.txt").unwrap()
+    }
+
+    fn parse_cards(labels: &str) -> Vec<Card> {
+        labels.chars().map(Card::new).collect()
+    }
+
+    fn get_cards() -> Vec<Hand> {
+        get_input()
+            .lines()
+            .map(|line| line.parse::<Hand>().unwrap())
+            .collect_vec()
+    }
+
+    mod part_one {
+        use crate::{
+            get_total_winnings, get_total_winnings_with, test::get_cards, Card, Hand, Rules,
+        };
+
+        #[test]
+        fn total_winnings() {
+            let mut cards = get_cards();
+            cards.sort();
+            assert_eq!(get_total_winnings(&cards), 6440);
+            assert_eq!(get_total_winnings_with(&cards, &Rules::STANDARD), 6440);
+        }
+
+        #[test]
+        fn custom_wild_cards() {
+            let rules = Rules::wild(&[Card::new('T'), Card::new('J')]);
+            let hand = "KTJJT 220"
+                .parse::<Hand>()
+                .unwrap()
+                .with_rules(rules.clone());
+            assert_eq!(hand.get_type().counts(), [5]);
+
+            let hand = "TTJJT 220"
+                .parse::<Hand>()
+                .unwrap()
+                .with_rules(rules.clone());
+            assert_eq!(hand.get_type().counts(), [5]);
+            assert!(hand < "22222 1".parse::<Hand>().unwrap().with_rules(rules));
+        }
+    }
+
+    mod custom_game {
+        use crate::{get_total_winnings_with, parse_hands, Rules};
+        use common::ParseError;
+
+        const RULES: &str = "cards = 123456\nwild = 1\nhand size = 3\n";
+
+        #[test]
+        fn total_winnings() {
+            let rules = RULES.parse::<Rules>().unwrap();
+            let hands = parse_hands("234 1\n166 10\n551 100\n322 1000\n", &rules).unwrap();
+            assert_eq!(hands[1].get_type().counts(), [3]);
+            // 234 < 322 < 166 < 551, the joker being weaker than any other card
+            assert_eq!(
+                get_total_winnings_with(&hands, &rules),
+                1 + 2 * 1000 + 3 * 10 + 4 * 100
+            );
+        }
+
+        #[test]
+        fn parse_errors() {
+            let rules = RULES.parse::<Rules>().unwrap();
+            let err = parse_hands("234 1\n2345 2\n", &rules).unwrap_err();
+            assert_eq!(err, ParseError::new(1, 0..4, "three cards"));
+
+            let err = parse_hands("237 1\n", &rules).unwrap_err();
+            assert_eq!(err, ParseError::new(0, 2..3, "a card: `1`-`6`"));
+        }
+    }
+
+    mod part_two {
+        use crate::{
+            get_total_winnings,
+            test::{self, parse_cards},
+            Hand, Rules,
+        };
+        use common::ParseError;
+
+        fn get_cards() -> Vec<Hand> {
+            test::get_cards()
+                .into_iter()
+                .map(|hand| hand.with_rules(Rules::JOKERS))
+                .collect()
+        }
+
+        #[test]
+        fn type_compare() {
+            // From high card up to five of a kind
+            let types = [
+                "23456", "A23A4", "23432", "TTT98", "23332", "AA8AA", "AAAAA",
+            ]
+            .map(|hand| get_hand_type(hand).get_type());
+            assert!(types.windows(2).all(|pair| pair[0] < pair[1]));
+        }
+
+        #[test]
+        fn parse() {
+            let hand = "32T3K 765".parse::<Hand>().unwrap();
+            assert_eq!(
+                hand,
+                Hand {
+                    points: 765,
+                    cards: parse_cards("32T3K"),
+                    rules: Rules::STANDARD,
+                }
+            )
+        }
+
+        #[test]
+        fn parse_errors() {
+            let err = "32X3K 765".parse::<Hand>().unwrap_err();
+            assert_eq!(
+                err,
+                ParseError::new(0, 2..3, "a card: `2`-`9`, `T`, `J`, `Q`, `K` or `A`")
+            );
+
+            let err = "32T3 765".parse::<Hand>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 0..4, "five cards"));
+
+            let err = "32T3K".parse::<Hand>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 5..6, "a bid"));
+        }
+
+        fn get_hand_type(hand_word: &str) -> Hand {
+            let line = format!("{hand_word} 123");
+            line.parse::<Hand>().unwrap().with_rules(Rules::JOKERS)
+        }
+
+        #[test]
+        fn type_five() {
+            let card = get_hand_type("AAAAA");
+            assert_eq!(card.get_type().counts(), [5]);
+        }
+
+        #[test]
+        fn type_four() {
+            let card = get_hand_type("AA8AA");
+            assert_eq!(card.get_type().counts(), [4, 1]);
+        }
+
+        #[test]
+        fn type_house() {
+            let card = get_hand_type("23332");
+            assert_eq!(card.get_type().counts(), [3, 2]);
+        }
+
+        #[test]
+        fn type_three() {
+            let card = get_hand_type("TTT98");
+            assert_eq!(card.get_type().counts(), [3, 1, 1]);
+        }
+
+        #[test]
+        fn type_two() {
+            let card = get_hand_type("23432");
+            assert_eq!(card.get_type().counts(), [2, 2, 1]);
+        }
+
+        #[test]
+        fn type_one() {
+            let card = get_hand_type("A23A4");
+            assert_eq!(card.get_type().counts(), [2, 1, 1, 1]);
+        }
+
+        #[test]
+        fn type_high() {
+            let card = get_hand_type("23456");
+            assert_eq!(card.get_type().counts(), [1, 1, 1, 1, 1]);
+        }
+
+        #[test]
+        fn ordering() {
+            let mut cards = get_cards();
+            cards.sort();
+            assert_eq!(
+                cards,
+                vec![
+                    Hand {
+                        cards: parse_cards("32T3K"),
+                        points: 765,
+                        rules: Rules::JOKERS,
+                    },
+                    Hand {
+                        cards: parse_cards("KK677"),
+                        points: 28,
+                        rules: Rules::JOKERS,
+                    },
+                    Hand {
+                        cards: parse_cards("T55J5"),
+                        points: 684,
+                        rules: Rules::JOKERS,
+                    },
+                    Hand {
+                        cards: parse_cards("QQQJA"),
+                        points: 483,
+                        rules: Rules::JOKERS,
+                    },
+                    Hand {
+                        cards: parse_cards("KTJJT"),
+                        points: 220,
+                        rules: Rules::JOKERS,
+                    },
+                ]
+            )
+        }
+
+        #[test]
+        fn total_winnings() {
+            let mut cards = get_cards();
+            cards.sort();
+            assert_eq!(get_total_winnings(&cards), 5905);
+        }
+    }
+}
@@ -0,0 +1,286 @@
+use crate::Card;
+use common::ParseError;
+use std::{borrow::Cow, str::FromStr};
+
+/// Cards of the standard deck, from the weakest to the strongest
+const STANDARD_CARDS: [Card; 13] = [
+    Card::new('2'),
+    Card::new('3'),
+    Card::new('4'),
+    Card::new('5'),
+    Card::new('6'),
+    Card::new('7'),
+    Card::new('8'),
+    Card::new('9'),
+    Card::new('T'),
+    Card::new('J'),
+    Card::new('Q'),
+    Card::new('K'),
+    Card::new('A'),
+];
+
+/// Rules of a game of Camel Cards: the deck, which of its cards are wild and
+/// how many cards make a hand
+///
+/// Wild cards act as whatever card makes the strongest hand type, but are
+/// weaker than every other card when breaking ties. The remaining cards keep
+/// the order of the deck.
+///
+/// Other games are described by a small config, one `key = value` per line:
+///
+/// ```text
+/// # Six cards, aces low, with both jokers and deuces wild
+/// cards = A23456789TJQK
+/// wild = J2
+/// hand size = 6
+/// ```
+///
+/// Every key is optional, falling back to the standard game without wild
+/// cards, and `#` starts a comment.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Rules {
+    /// Cards of the deck, from the weakest to the strongest
+    cards: Cow<'static, [Card]>,
+    wild: Cow<'static, [Card]>,
+    hand_size: usize,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        Rules::STANDARD
+    }
+}
+
+impl Rules {
+    /// No wild cards, `J` is a jack, as in part one
+    pub const STANDARD: Rules = Rules {
+        cards: Cow::Borrowed(&STANDARD_CARDS),
+        wild: Cow::Borrowed(&[]),
+        hand_size: 5,
+    };
+
+    /// `J` is a joker, as in part two
+    pub const JOKERS: Rules = Rules {
+        cards: Cow::Borrowed(&STANDARD_CARDS),
+        wild: Cow::Borrowed(&[Card::new('J')]),
+        hand_size: 5,
+    };
+
+    /// Standard game where every card in `cards` is wild
+    pub fn wild(cards: &[Card]) -> Rules {
+        Rules {
+            wild: Cow::Owned(cards.to_vec()),
+            ..Rules::STANDARD
+        }
+    }
+
+    /// Cards of the deck, from the weakest to the strongest, before taking
+    /// the wild cards out
+    pub fn cards(&self) -> &[Card] {
+        &self.cards
+    }
+
+    pub fn hand_size(&self) -> usize {
+        self.hand_size
+    }
+
+    pub fn contains(&self, card: Card) -> bool {
+        self.cards.contains(&card)
+    }
+
+    pub fn is_wild(&self, card: Card) -> bool {
+        self.wild.contains(&card)
+    }
+
+    /// Strength of `card` when breaking ties between hands of the same type
+    ///
+    /// # Panics
+    ///
+    /// Panics if the card is not in the deck
+    pub fn strength(&self, card: Card) -> usize {
+        let position = self
+            .cards
+            .iter()
+            .position(|&c| c == card)
+            .unwrap_or_else(|| panic!("{card} should be in the deck"));
+        if self.is_wild(card) {
+            position
+        } else {
+            self.cards.len() + position
+        }
+    }
+
+    /// What a card of this deck looks like, for parse errors, e.g.
+    /// ``a card: `2`-`9`, `T`, `J`, `Q`, `K` or `A` ``
+    pub(crate) fn describe_card(&self) -> String {
+        let mut items = Vec::new();
+        let mut i = 0;
+        while i < self.cards.len() {
+            // Runs of three or more consecutive digits are shown as a span
+            let mut end = i;
+            while end + 1 < self.cards.len()
+                && self.cards[end].label().is_ascii_digit()
+                && self.cards[end + 1].label() as u32 == self.cards[end].label() as u32 + 1
+            {
+                end += 1;
+            }
+            if end - i >= 2 {
+                items.push(format!("`{}`-`{}`", self.cards[i], self.cards[end]));
+                i = end + 1;
+            } else {
+                items.push(format!("`{}`", self.cards[i]));
+                i += 1;
+            }
+        }
+
+        match items.split_last() {
+            Some((last, [])) => format!("a card: {last}"),
+            Some((last, rest)) => format!("a card: {} or {last}", rest.join(", ")),
+            None => "a card".to_string(),
+        }
+    }
+}
+
+impl FromStr for Rules {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cards = Rules::STANDARD.cards.to_vec();
+        let mut wild = None;
+        let mut hand_size = Rules::STANDARD.hand_size;
+
+        for line in s.lines() {
+            let content = line.split('#').next().unwrap_or_default().trim();
+            if content.is_empty() {
+                continue;
+            }
+            let (key, value) = content
+                .split_once('=')
+                .ok_or_else(|| ParseError::at(s, content, "`<key> = <value>`"))?;
+            let (key, value) = (key.trim(), value.trim());
+
+            match key {
+                "cards" => {
+                    cards = Vec::new();
+                    for (i, label) in value.char_indices() {
+                        if label.is_whitespace() {
+                            continue;
+                        }
+                        let card = Card::new(label);
+                        if cards.contains(&card) {
+                            let token = &value[i..i + label.len_utf8()];
+                            return Err(ParseError::at(s, token, "a card not in the deck yet"));
+                        }
+                        cards.push(card);
+                    }
+                    if cards.is_empty() {
+                        return Err(ParseError::after(s, key, "at least one card"));
+                    }
+                }
+                "wild" => wild = Some(value),
+                "hand size" => {
+                    hand_size = value
+                        .parse()
+                        .ok()
+                        .filter(|&size| size > 0)
+                        .ok_or_else(|| ParseError::at(s, value, "a hand size above zero"))?;
+                }
+                _ => return Err(ParseError::at(s, key, "`cards`, `wild` or `hand size`")),
+            }
+        }
+
+        // Wild cards are checked last, as the deck may come after them
+        let mut wild_cards = Vec::new();
+        for (i, label) in wild.unwrap_or_default().char_indices() {
+            let card = Card::new(label);
+            if label.is_whitespace() || wild_cards.contains(&card) {
+                continue;
+            }
+            if !cards.contains(&card) {
+                let token = &wild.unwrap()[i..i + label.len_utf8()];
+                return Err(ParseError::at(s, token, "a card of the deck"));
+            }
+            wild_cards.push(card);
+        }
+
+        Ok(Rules {
+            cards: Cow::Owned(cards),
+            wild: Cow::Owned(wild_cards),
+            hand_size,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Rules;
+    use crate::Card;
+    use common::ParseError;
+
+    #[test]
+    fn strength() {
+        let rules = Rules::STANDARD;
+        assert!(rules.strength(Card::new('J')) > rules.strength(Card::new('T')));
+        assert!(rules.strength(Card::new('J')) < rules.strength(Card::new('Q')));
+
+        let rules = Rules::JOKERS;
+        assert!(rules.is_wild(Card::new('J')));
+        assert!(rules.strength(Card::new('J')) < rules.strength(Card::new('2')));
+
+        let rules = Rules::wild(&[Card::new('2'), Card::new('K')]);
+        assert!(!rules.is_wild(Card::new('J')));
+        assert!(rules.strength(Card::new('2')) < rules.strength(Card::new('K')));
+        assert!(rules.strength(Card::new('K')) < rules.strength(Card::new('3')));
+        assert!(rules.strength(Card::new('A')) > rules.strength(Card::new('Q')));
+    }
+
+    #[test]
+    fn parse() {
+        let rules =
+            "# aces low\ncards = A23456789TJQK\nwild = J2 # jokers and deuces\nhand size = 6\n"
+                .parse::<Rules>()
+                .unwrap();
+        assert_eq!(rules.hand_size(), 6);
+        assert_eq!(rules.cards()[0], Card::new('A'));
+        assert!(rules.is_wild(Card::new('J')) && rules.is_wild(Card::new('2')));
+        assert!(rules.strength(Card::new('A')) < rules.strength(Card::new('3')));
+
+        assert_eq!("".parse::<Rules>().unwrap(), Rules::STANDARD);
+        assert_eq!("wild = J".parse::<Rules>().unwrap(), Rules::JOKERS);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "cards = ABCA".parse::<Rules>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(0, 11..12, "a card not in the deck yet")
+        );
+
+        let err = "cards = ABC\nwild = X".parse::<Rules>().unwrap_err();
+        assert_eq!(err, ParseError::new(1, 7..8, "a card of the deck"));
+
+        let err = "hand size = 0".parse::<Rules>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 12..13, "a hand size above zero"));
+
+        let err = "jokers = J".parse::<Rules>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(0, 0..6, "`cards`, `wild` or `hand size`")
+        );
+
+        let err = "wild J".parse::<Rules>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 0..6, "`<key> = <value>`"));
+    }
+
+    #[test]
+    fn describe_card() {
+        let rules = Rules::STANDARD;
+        assert_eq!(
+            rules.describe_card(),
+            "a card: `2`-`9`, `T`, `J`, `Q`, `K` or `A`"
+        );
+        let rules = "cards = 12AB".parse::<Rules>().unwrap();
+        assert_eq!(rules.describe_card(), "a card: `1`, `2`, `A` or `B`");
+    }
+}
@@ -0,0 +1,194 @@
+//! Arrival times of ghosts walking the map forever
+//!
+//! A ghost's state is the node it stands on together with the next
+//! instruction to follow. There are finitely many states, so every walk ends
+//! up repeating a cycle of them after a prefix, and the steps at which it
+//! stands on an end node are a few isolated steps in the prefix plus a few
+//! residues modulo the cycle length.
+
+use crate::Unreachable;
+
+/// Steps at which a single ghost stands on an end node
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle {
+    /// Step at which the walk enters the cycle, the length of the prefix
+    pub start: u64,
+    /// Steps taken to go around the cycle once
+    pub length: u64,
+    /// Steps before `start + length` at which the ghost stands on an end
+    /// node, in increasing order
+    pub hits: Vec<u64>,
+}
+
+impl Cycle {
+    /// Hits before the cycle, which never happen again
+    pub fn prefix_hits(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits.iter().copied().filter(|&hit| hit < self.start)
+    }
+
+    /// Hits in the first turn around the cycle, repeated every `length` steps
+    pub fn cycle_hits(&self) -> impl Iterator<Item = u64> + '_ {
+        self.hits.iter().copied().filter(|&hit| hit >= self.start)
+    }
+
+    /// Whether the ghost stands on an end node after `step` steps
+    pub fn is_hit(&self, step: u64) -> bool {
+        if step < self.start + self.length {
+            return self.hits.binary_search(&step).is_ok();
+        }
+        let offset = self.start + (step - self.start) % self.length;
+        self.hits.binary_search(&offset).is_ok()
+    }
+}
+
+/// First step, after at least one, at which every ghost stands on an end node
+/// at once
+///
+/// Arrivals before every ghost is in its cycle are checked one by one, the
+/// later ones are solved as a system of congruences.
+///
+/// Fails with [`Unreachable::Ghosts`] if that never happens or there are no
+/// ghosts, and with [`Unreachable::Overflow`] if the cycles are too long to
+/// combine or the step does not fit in 64 bits.
+pub fn first_common_hit(cycles: &[Cycle]) -> Result<u64, Unreachable> {
+    let latest = cycles
+        .iter()
+        .max_by_key(|cycle| cycle.start)
+        .ok_or(Unreachable::Ghosts)?;
+    let periodic_from = latest.start.max(1);
+
+    // Before `periodic_from` the latest ghost is still in its prefix
+    let early = latest
+        .prefix_hits()
+        .filter(|&step| step >= 1)
+        .find(|&step| cycles.iter().all(|cycle| cycle.is_hit(step)));
+    if let Some(step) = early {
+        return Ok(step);
+    }
+
+    // From then on every ghost repeats, keep the residues all of them allow
+    let mut residues = vec![(0, 1)];
+    for cycle in cycles {
+        let length = i128::from(cycle.length);
+        residues = residues
+            .iter()
+            .flat_map(|&residue| {
+                cycle
+                    .cycle_hits()
+                    .filter_map(move |hit| crt(residue, (i128::from(hit) % length, length)))
+            })
+            .collect::<Result<_, _>>()?;
+        residues.sort_unstable();
+        residues.dedup();
+    }
+
+    let periodic_from = i128::from(periodic_from);
+    residues
+        .into_iter()
+        .map(|(residue, modulus)| periodic_from + (residue - periodic_from).rem_euclid(modulus))
+        .min()
+        .ok_or(Unreachable::Ghosts)
+        .and_then(|step| u64::try_from(step).map_err(|_| Unreachable::Overflow))
+}
+
+/// Greatest common divisor `g` of `a` and `b`, with `x` and `y` such that
+/// `a * x + b * y = g`
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+/// Numbers congruent to both `a` modulo `m` and `b` modulo `n`, as a residue
+/// modulo the least common multiple, or `None` if there are none
+///
+/// The moduli need not be coprime, but their least common multiple must fit
+/// in an `i128`.
+fn crt((a, m): (i128, i128), (b, n): (i128, i128)) -> Option<Result<(i128, i128), Unreachable>> {
+    let (g, x, _) = extended_gcd(m, n);
+    if (b - a) % g != 0 {
+        return None;
+    }
+    let Some(lcm) = (m / g).checked_mul(n) else {
+        return Some(Err(Unreachable::Overflow));
+    };
+    let n_g = n / g;
+    // m * k ≡ b - a (mod n) for k ≡ (b - a) / g * x (mod n / g)
+    let k = ((b - a) / g).rem_euclid(n_g) * x.rem_euclid(n_g) % n_g;
+    Some(Ok(((a + m * k).rem_euclid(lcm), lcm)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cycle(start: u64, length: u64, hits: &[u64]) -> Cycle {
+        Cycle {
+            start,
+            length,
+            hits: hits.to_vec(),
+        }
+    }
+
+    #[test]
+    fn is_hit() {
+        let cycle = cycle(2, 3, &[1, 3]);
+        let hits = (0..12)
+            .filter(|&step| cycle.is_hit(step))
+            .collect::<Vec<_>>();
+        assert_eq!(hits, [1, 3, 6, 9]);
+    }
+
+    #[test]
+    fn chinese_remainder() {
+        assert_eq!(crt((2, 5), (3, 4)), Some(Ok((7, 20))));
+        assert_eq!(crt((3, 6), (1, 4)), Some(Ok((9, 12))));
+        assert_eq!(crt((0, 2), (3, 6)), None);
+        assert_eq!(crt((0, 1), (4, 7)), Some(Ok((4, 7))));
+    }
+
+    #[test]
+    fn common_hit() {
+        // Not the least common multiple of the first hits, 2 and 3
+        let cycles = [cycle(0, 5, &[2]), cycle(0, 4, &[3])];
+        assert_eq!(first_common_hit(&cycles), Ok(7));
+
+        // Only in the prefix of one ghost
+        let cycles = [cycle(5, 1, &[3]), cycle(0, 3, &[0])];
+        assert_eq!(first_common_hit(&cycles), Ok(3));
+
+        // Several hits per cycle
+        let cycles = [cycle(1, 2, &[2]), cycle(1, 6, &[3, 6])];
+        assert_eq!(first_common_hit(&cycles), Ok(6));
+
+        // Both ghosts start on an end node, which is not an arrival
+        let cycles = [cycle(0, 2, &[0]), cycle(0, 3, &[0])];
+        assert_eq!(first_common_hit(&cycles), Ok(6));
+    }
+
+    #[test]
+    fn no_common_hit() {
+        let cycles = [cycle(0, 2, &[0]), cycle(0, 2, &[1])];
+        assert_eq!(first_common_hit(&cycles), Err(Unreachable::Ghosts));
+
+        let cycles = [cycle(4, 1, &[2]), cycle(0, 1, &[])];
+        assert_eq!(first_common_hit(&cycles), Err(Unreachable::Ghosts));
+
+        assert_eq!(first_common_hit(&[]), Err(Unreachable::Ghosts));
+    }
+
+    #[test]
+    fn overflow() {
+        // Odd steps that are one less than a multiple of 2^64 - 1, the first
+        // one being 2^65 - 3
+        let cycles = [cycle(0, u64::MAX, &[u64::MAX - 1]), cycle(0, 2, &[1])];
+        assert_eq!(first_common_hit(&cycles), Err(Unreachable::Overflow));
+
+        // Consecutive lengths are coprime, with a common multiple past 2^127
+        let cycles = [cycle(0, u64::MAX, &[2]), cycle(0, u64::MAX - 1, &[3])];
+        assert_eq!(first_common_hit(&cycles), Err(Unreachable::Overflow));
+    }
+}
@@ -0,0 +1,149 @@
+//! Graphviz export of the desert network
+//!
+//! Nodes and edges are written sorted by label, so the same map always gives
+//! the same graph.
+
+use crate::{Instruction, Map, NodeId};
+use std::{collections::HashSet, fmt};
+
+const START_COLOR: &str = "palegreen";
+const END_COLOR: &str = "lightcoral";
+const PATH_COLOR: &str = "royalblue";
+
+/// DOT graph of a map, written by its `Display` implementation
+///
+/// Edges are labeled with the instructions that follow them, `LR` when both
+/// lead to the same node. Nodes ending in `A` and `Z` are filled, and the
+/// steps of a path, if any, are drawn in blue.
+pub struct Dot<'a> {
+    map: &'a Map,
+    /// Edges taken by the path, as the node left and the instruction followed
+    path: HashSet<(NodeId, Instruction)>,
+}
+
+impl<'a> Dot<'a> {
+    pub fn new(map: &'a Map) -> Self {
+        Self {
+            map,
+            path: HashSet::new(),
+        }
+    }
+
+    /// Highlights the steps from each node of `path` to the next one, `path`
+    /// starting on the first instruction of the map
+    pub fn with_path(mut self, path: &[NodeId]) -> Self {
+        let instructions = self.map.instructions.iter().cycle();
+        self.path = path
+            .windows(2)
+            .zip(instructions)
+            .map(|(step, &instruction)| (step[0], instruction))
+            .collect();
+        self
+    }
+}
+
+impl fmt::Display for Dot<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let map = self.map;
+        let mut nodes = map.nodes().collect::<Vec<_>>();
+        nodes.sort_unstable_by_key(|&node| map.label(node));
+
+        writeln!(f, "digraph map {{")?;
+        writeln!(f, "    node [shape=circle];")?;
+        for &node in &nodes {
+            let label = map.label(node);
+            if label.ends_with('A') {
+                writeln!(f, "    {label:?} [style=filled, fillcolor={START_COLOR}];")?;
+            } else if label.ends_with('Z') {
+                writeln!(f, "    {label:?} [style=filled, fillcolor={END_COLOR}];")?;
+            }
+        }
+
+        for &node in &nodes {
+            let left = map.next(node, Instruction::Left);
+            let right = map.next(node, Instruction::Right);
+            let edges = if left == right {
+                vec![(left, "LR", vec![Instruction::Left, Instruction::Right])]
+            } else {
+                vec![
+                    (left, "L", vec![Instruction::Left]),
+                    (right, "R", vec![Instruction::Right]),
+                ]
+            };
+            for (to, name, instructions) in edges {
+                let style = match instructions
+                    .into_iter()
+                    .any(|instruction| self.path.contains(&(node, instruction)))
+                {
+                    true => format!(", color={PATH_COLOR}, fontcolor={PATH_COLOR}, penwidth=2"),
+                    false => String::new(),
+                };
+                writeln!(
+                    f,
+                    "    {:?} -> {:?} [label={name:?}{style}];",
+                    map.label(node),
+                    map.label(to)
+                )?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Map;
+
+    const MAP: &str = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)";
+
+    #[test]
+    fn export() {
+        let map = MAP.parse::<Map>().unwrap();
+        assert_eq!(
+            map.dot().to_string(),
+            r#"digraph map {
+    node [shape=circle];
+    "AAA" [style=filled, fillcolor=palegreen];
+    "ZZZ" [style=filled, fillcolor=lightcoral];
+    "AAA" -> "BBB" [label="LR"];
+    "BBB" -> "AAA" [label="L"];
+    "BBB" -> "ZZZ" [label="R"];
+    "ZZZ" -> "ZZZ" [label="LR"];
+}
+"#
+        );
+    }
+
+    #[test]
+    fn sorted_by_label() {
+        let map = "L\n\nZZZ = (AAA, AAA)\nAAA = (ZZZ, ZZZ)"
+            .parse::<Map>()
+            .unwrap();
+        let reordered = "L\n\nAAA = (ZZZ, ZZZ)\nZZZ = (AAA, AAA)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.dot().to_string(), reordered.dot().to_string());
+    }
+
+    #[test]
+    fn export_path() {
+        let map = MAP.parse::<Map>().unwrap();
+        let path = map.path_to_zzz().unwrap();
+        let labels = path.iter().map(|&node| map.label(node)).collect::<Vec<_>>();
+        assert_eq!(labels, ["AAA", "BBB", "AAA", "BBB", "AAA", "BBB", "ZZZ"]);
+
+        let dot = map.dot().with_path(&path).to_string();
+        let highlighted = dot
+            .lines()
+            .filter(|line| line.contains("royalblue"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            highlighted,
+            [
+                r#"    "AAA" -> "BBB" [label="LR", color=royalblue, fontcolor=royalblue, penwidth=2];"#,
+                r#"    "BBB" -> "AAA" [label="L", color=royalblue, fontcolor=royalblue, penwidth=2];"#,
+                r#"    "BBB" -> "ZZZ" [label="R", color=royalblue, fontcolor=royalblue, penwidth=2];"#,
+            ]
+        );
+    }
+}
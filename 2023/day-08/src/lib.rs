@@ -0,0 +1,459 @@
+use common::{ParseError, Solution};
+use std::{
+    collections::HashMap,
+    fmt::{self, Display},
+    str::FromStr,
+};
+use strum_macros::EnumString;
+
+pub mod cycle;
+pub mod dot;
+
+use cycle::Cycle;
+use dot::Dot;
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Map;
+    type Error = Unreachable;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Map>()
+    }
+
+    fn part_one(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        map.walk_to_zzz().ok_or(Unreachable::Zzz)
+    }
+
+    fn part_two(map: &Self::Input) -> Result<impl Display, Self::Error> {
+        map.walk_to_z_par()
+    }
+}
+
+/// The walk never gets where it is going
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unreachable {
+    /// The map has no `AAA` or `ZZZ` node, or the walk never gets there
+    Zzz,
+    /// The ghosts never all stand on nodes ending in `Z` at once
+    Ghosts,
+    /// The ghosts walk cycles too long to tell when they meet in 64 bits
+    Overflow,
+}
+
+impl fmt::Display for Unreachable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unreachable::Zzz => write!(f, "there is no walk from AAA to ZZZ"),
+            Unreachable::Ghosts => {
+                write!(f, "the ghosts never all reach a node ending in Z at once")
+            }
+            Unreachable::Overflow => {
+                write!(
+                    f,
+                    "the ghosts take too many steps to meet to count in 64 bits"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, EnumString)]
+pub enum Instruction {
+    #[strum(serialize = "L")]
+    Left = 0,
+    #[strum(serialize = "R")]
+    Right = 1,
+}
+
+/// Labels of the nodes reached from a node, as written in the map
+#[derive(Debug, PartialEq, Clone)]
+pub struct Node {
+    left: String,
+    right: String,
+}
+
+impl FromStr for Node {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (left, right) = s
+            .strip_prefix('(')
+            .and_then(|s| s.strip_suffix(')'))
+            .and_then(|s| s.split_once(", "))
+            .ok_or_else(|| ParseError::at(s, s, "`(left, right)`"))?;
+        Ok(Self {
+            left: left.to_string(),
+            right: right.to_string(),
+        })
+    }
+}
+
+/// Index of a node in the map, in the order the nodes are defined
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeId(u32);
+
+impl NodeId {
+    pub fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
+/// Network of nodes compiled to a table of transitions between node ids,
+/// keeping the labels only to look them up and report them
+#[derive(Debug, PartialEq)]
+pub struct Map {
+    instructions: Vec<Instruction>,
+    /// Label of each node, indexed by id
+    labels: Vec<String>,
+    ids: HashMap<String, NodeId>,
+    /// Nodes reached going left and right from each node, indexed by id
+    transitions: Vec<[NodeId; 2]>,
+}
+
+impl Map {
+    pub fn id(&self, label: &str) -> Option<NodeId> {
+        self.ids.get(label).copied()
+    }
+
+    pub fn label(&self, id: NodeId) -> &str {
+        &self.labels[id.index()]
+    }
+
+    /// Every node, in the order they are defined
+    pub fn nodes(&self) -> impl Iterator<Item = NodeId> {
+        (0..self.labels.len() as u32).map(NodeId)
+    }
+
+    pub fn next(&self, from: NodeId, instruction: Instruction) -> NodeId {
+        self.transitions[from.index()][instruction as usize]
+    }
+
+    /// Nodes reached after each step following the instructions from `from`,
+    /// forever
+    pub fn walk(&self, from: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.instructions
+            .iter()
+            .cycle()
+            .scan(from, |node, &instruction| {
+                *node = self.next(*node, instruction);
+                Some(*node)
+            })
+    }
+
+    /// Steps from `AAA` to `ZZZ`, or `None` if the map has no such nodes or
+    /// the walk never gets there
+    pub fn walk_to_zzz(&self) -> Option<usize> {
+        let (_, to, mut walk) = self.bounded_walk_from_aaa()?;
+        walk.position(|node| node == to).map(|steps| steps + 1)
+    }
+
+    /// Nodes visited from `AAA` to `ZZZ`, both included, or `None` if the
+    /// map has no such nodes or the walk never gets there
+    pub fn path_to_zzz(&self) -> Option<Vec<NodeId>> {
+        let (from, to, walk) = self.bounded_walk_from_aaa()?;
+        let mut path = vec![from];
+        for node in walk {
+            path.push(node);
+            if node == to {
+                return Some(path);
+            }
+        }
+        None
+    }
+
+    /// `AAA`, `ZZZ` and the walk from `AAA`, cut where a state has repeated
+    /// without reaching `ZZZ`
+    fn bounded_walk_from_aaa(&self) -> Option<(NodeId, NodeId, impl Iterator<Item = NodeId> + '_)> {
+        let from = self.id("AAA")?;
+        let to = self.id("ZZZ")?;
+        let states = self.labels.len() * self.instructions.len();
+        Some((from, to, self.walk(from).take(states)))
+    }
+
+    /// Graphviz export of the map
+    pub fn dot(&self) -> Dot<'_> {
+        Dot::new(self)
+    }
+
+    fn is_end(&self, node: NodeId) -> bool {
+        self.label(node).ends_with('Z')
+    }
+
+    /// Walks from `from` until a state repeats, recording the steps that
+    /// land on a node ending in `Z`
+    pub fn cycle(&self, from: NodeId) -> Cycle {
+        let states = self.labels.len() * self.instructions.len();
+        let mut seen = vec![None; states];
+        let mut hits = Vec::new();
+        let mut node = from;
+
+        for step in 0.. {
+            let index = step as usize % self.instructions.len();
+            let state = node.index() * self.instructions.len() + index;
+            if let Some(start) = seen[state] {
+                return Cycle {
+                    start,
+                    length: step - start,
+                    hits,
+                };
+            }
+            seen[state] = Some(step);
+            if self.is_end(node) {
+                hits.push(step);
+            }
+            node = self.next(node, self.instructions[index]);
+        }
+        unreachable!("the number of states is finite")
+    }
+
+    /// Steps until the ghosts starting on every node ending in `A` all stand
+    /// on nodes ending in `Z` at once, see [`cycle::first_common_hit`]
+    pub fn walk_to_z_par(&self) -> Result<u64, Unreachable> {
+        let cycles = self
+            .nodes()
+            .filter(|&node| self.label(node).ends_with('A'))
+            .map(|node| self.cycle(node))
+            .collect::<Vec<_>>();
+        cycle::first_common_hit(&cycles)
+    }
+}
+
+impl FromStr for Map {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut lines = s.lines();
+        let instructions_line = lines.next().unwrap_or(s);
+        let instructions = instructions_line
+            .char_indices()
+            .map(|(i, c)| {
+                c.to_string().parse().map_err(|_| {
+                    let instruction = &instructions_line[i..i + c.len_utf8()];
+                    ParseError::at(s, instruction, "`L` or `R`")
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if instructions.is_empty() {
+            return Err(ParseError::at(s, instructions_line, "`L` or `R`"));
+        }
+
+        let lines = lines
+            .skip(1)
+            .map(|line| {
+                let (el, node_str) = line
+                    .split_once(" = ")
+                    .ok_or_else(|| ParseError::after(s, line, "` = `"))?;
+                let node = node_str
+                    .parse::<Node>()
+                    .map_err(|err| err.within(s, node_str))?;
+                Ok((el, node_str, node))
+            })
+            .collect::<Result<Vec<_>, ParseError>>()?;
+
+        // Ids are given in the order the nodes are defined
+        let mut labels = Vec::with_capacity(lines.len());
+        let mut ids = HashMap::with_capacity(lines.len());
+        for (el, ..) in &lines {
+            let id = NodeId(labels.len() as u32);
+            if ids.insert(el.to_string(), id).is_some() {
+                return Err(ParseError::at(s, el, "a node not defined yet"));
+            }
+            labels.push(el.to_string());
+        }
+
+        let mut transitions = Vec::with_capacity(lines.len());
+        for (_, node_str, node) in &lines {
+            let left = &node_str[1..1 + node.left.len()];
+            let right = &node_str[node_str.len() - 1 - node.right.len()..node_str.len() - 1];
+            let resolve = |label: &str| {
+                ids.get(label)
+                    .copied()
+                    .ok_or_else(|| ParseError::at(s, label, "a node defined in the map"))
+            };
+            transitions.push([resolve(left)?, resolve(right)?]);
+        }
+
+        Ok(Self {
+            instructions,
+            labels,
+            ids,
+            transitions,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use crate::{cycle::Cycle, Day08, Instruction, Map, Unreachable};
+    use common::{ParseError, Solution};
+
+    fn get_map() -> Map {
+        fs::read_to_string("./example.txt")
+            .unwrap()
+            .parse::<Map>()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        let map = get_map();
+        assert_eq!(map.instructions, [Instruction::Left, Instruction::Right]);
+        let nodes = map
+            .nodes()
+            .map(|node| {
+                (
+                    map.label(node),
+                    map.label(map.next(node, Instruction::Left)),
+                    map.label(map.next(node, Instruction::Right)),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            nodes,
+            [
+                ("11A", "11B", "XXX"),
+                ("11B", "XXX", "11Z"),
+                ("11Z", "11B", "XXX"),
+                ("22A", "22B", "XXX"),
+                ("22B", "22C", "22C"),
+                ("22C", "22Z", "22Z"),
+                ("22Z", "22B", "22B"),
+                ("XXX", "XXX", "XXX"),
+            ]
+        );
+    }
+
+    #[test]
+    fn interned_ids() {
+        let map = get_map();
+        let id = map.id("22C").unwrap();
+        assert_eq!(id.index(), 5);
+        assert_eq!(map.label(id), "22C");
+        assert_eq!(map.id("ZZZ"), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "LXR\n\nAAA = (AAA, AAA)".parse::<Map>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 1..2, "`L` or `R`"));
+
+        let err = "".parse::<Map>().unwrap_err();
+        assert_eq!(err, ParseError::new(0, 0..0, "`L` or `R`"));
+
+        let err = "LR\n\nAAA = (AAA AAA)".parse::<Map>().unwrap_err();
+        assert_eq!(err, ParseError::new(2, 6..15, "`(left, right)`"));
+
+        let err = "LR\n\nAAA = (AAA, BBB)".parse::<Map>().unwrap_err();
+        assert_eq!(err, ParseError::new(2, 12..15, "a node defined in the map"));
+
+        let err = "LR\n\nAAA = (AAA, AAA)\nAAA = (AAA, AAA)"
+            .parse::<Map>()
+            .unwrap_err();
+        assert_eq!(err, ParseError::new(3, 0..3, "a node not defined yet"));
+    }
+
+    #[test]
+    fn walk_to_zzz() {
+        let map = "RL\n\n\
+            AAA = (BBB, CCC)\nBBB = (DDD, EEE)\nCCC = (ZZZ, GGG)\nDDD = (DDD, DDD)\n\
+            EEE = (EEE, EEE)\nGGG = (GGG, GGG)\nZZZ = (ZZZ, ZZZ)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_zzz(), Some(2));
+
+        let map = "LLR\n\nAAA = (BBB, BBB)\nBBB = (AAA, ZZZ)\nZZZ = (ZZZ, ZZZ)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_zzz(), Some(6));
+    }
+
+    #[test]
+    fn walk_to_zzz_never() {
+        // The example of part two has no `AAA`
+        let map = get_map();
+        assert_eq!(map.walk_to_zzz(), None);
+        assert_eq!(Day08::part_one(&map).err(), Some(Unreachable::Zzz));
+
+        let map = "LR\n\nAAA = (BBB, BBB)\nBBB = (AAA, AAA)\nZZZ = (ZZZ, ZZZ)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_zzz(), None);
+    }
+
+    #[test]
+    fn walk_par() {
+        let map = get_map();
+        let steps = map.walk_to_z_par();
+        assert_eq!(steps, Ok(6));
+    }
+
+    #[test]
+    fn cycles() {
+        let map = get_map();
+        assert_eq!(
+            map.cycle(map.id("11A").unwrap()),
+            Cycle {
+                start: 1,
+                length: 2,
+                hits: vec![2]
+            }
+        );
+        assert_eq!(
+            map.cycle(map.id("22A").unwrap()),
+            Cycle {
+                start: 1,
+                length: 6,
+                hits: vec![3, 6]
+            }
+        );
+    }
+
+    /// Moves every ghost at once until they all stand on an end node
+    fn walk_together(map: &Map) -> u64 {
+        let mut nodes = map
+            .nodes()
+            .filter(|&node| map.label(node).ends_with('A'))
+            .collect::<Vec<_>>();
+        for (step, &instruction) in (1..).zip(map.instructions.iter().cycle()) {
+            for node in &mut nodes {
+                *node = map.next(*node, instruction);
+            }
+            if nodes.iter().all(|&node| map.label(node).ends_with('Z')) {
+                return step;
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn walk_par_out_of_phase() {
+        // First arrivals after 2 and 3 steps, but they only meet after 7
+        let map = "L\n\n\
+            11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11C, 11C)\n\
+            11C = (11D, 11D)\n11D = (11A, 11A)\n\
+            22A = (22B, 22B)\n22B = (22C, 22C)\n22C = (22Z, 22Z)\n22Z = (22A, 22A)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_z_par(), Ok(7));
+        assert_eq!(walk_together(&map), 7);
+
+        // An end node left behind before the cycle
+        let map = "L\n\n\
+            11A = (11Z, 11Z)\n11Z = (11B, 11B)\n11B = (11B, 11B)\n\
+            22A = (22Z, 22Z)\n22Z = (22A, 22A)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_z_par(), Ok(1));
+
+        let map = "L\n\n\
+            11A = (11B, 11B)\n11B = (11Z, 11Z)\n11Z = (11B, 11B)\n\
+            22A = (22Z, 22Z)\n22Z = (22A, 22A)"
+            .parse::<Map>()
+            .unwrap();
+        assert_eq!(map.walk_to_z_par(), Err(Unreachable::Ghosts));
+    }
+}
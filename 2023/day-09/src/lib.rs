@@ -0,0 +1,178 @@
+use common::{ParseError, Solution};
+use std::{fmt::Display, str::FromStr};
+
+pub mod polynomial;
+pub mod table;
+
+use polynomial::{Overflow, Polynomial};
+use table::DifferenceTable;
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Sequence>;
+    type Error = Overflow;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input
+            .lines()
+            .map(|line| {
+                line.parse::<Sequence>()
+                    .map_err(|err| err.within(input, line))
+            })
+            .collect()
+    }
+
+    fn part_one(sequences: &Self::Input) -> Result<impl Display, Self::Error> {
+        sum(sequences.iter().map(Sequence::find_next))
+    }
+
+    fn part_two(sequences: &Self::Input) -> Result<impl Display, Self::Error> {
+        sum(sequences.iter().map(Sequence::find_previous))
+    }
+}
+
+/// Adds up extrapolated values, failing if any of them or their sum overflows
+pub fn sum(mut values: impl Iterator<Item = Result<i128, Overflow>>) -> Result<i128, Overflow> {
+    values.try_fold(0i128, |sum, value| sum.checked_add(value?).ok_or(Overflow))
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Sequence(Vec<i64>);
+
+impl Sequence {
+    /// Lowest degree polynomial taking the values of the sequence, the first
+    /// one at index 0
+    pub fn polynomial(&self) -> Result<Polynomial, Overflow> {
+        Polynomial::interpolate(&self.0)
+    }
+
+    /// Differences of the sequence down to a row of zeros, telling its degree
+    pub fn differences(&self) -> Result<DifferenceTable, Overflow> {
+        DifferenceTable::new(&self.0)
+    }
+
+    /// Value extrapolated at `index`, past either end of the sequence
+    pub fn value_at(&self, index: i128) -> Result<i128, Overflow> {
+        self.polynomial()?.evaluate(index)
+    }
+
+    pub fn find_next(&self) -> Result<i128, Overflow> {
+        self.value_at(self.0.len() as i128)
+    }
+
+    pub fn find_previous(&self) -> Result<i128, Overflow> {
+        self.value_at(-1)
+    }
+}
+
+impl FromStr for Sequence {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let sequence = s
+            .split_whitespace()
+            .map(|word| {
+                word.parse()
+                    .map_err(|_| ParseError::at(s, word, "a number"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if sequence.is_empty() {
+            return Err(ParseError::at(s, s, "a number"));
+        }
+        Ok(Self(sequence))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::Sequence;
+    use std::fs;
+
+    fn get_input() -> Vec<Sequence> {
+        fs::read_to_string("./example.txt")
+            .unwrap()
+            .lines()
+            .map(|line| line.parse().unwrap())
+            .collect()
+    }
+
+    mod part_one {
+        use super::get_input;
+        use crate::{polynomial::Overflow, Sequence};
+        use common::ParseError;
+
+        #[test]
+        fn parse() {
+            let sequences = get_input();
+            assert_eq!(
+                sequences,
+                vec![
+                    Sequence(vec![0, 3, 6, 9, 12, 15]),
+                    Sequence(vec![1, 3, 6, 10, 15, 21]),
+                    Sequence(vec![10, 13, 16, 21, 30, 45]),
+                ]
+            )
+        }
+
+        #[test]
+        fn parse_errors() {
+            let err = "0 3 6 9.5 12".parse::<Sequence>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 6..9, "a number"));
+
+            let err = "".parse::<Sequence>().unwrap_err();
+            assert_eq!(err, ParseError::new(0, 0..0, "a number"));
+        }
+
+        #[test]
+        fn find_next() {
+            let next_sum = crate::sum(get_input().iter().map(Sequence::find_next));
+            assert_eq!(next_sum, Ok(114));
+        }
+
+        #[test]
+        fn degrees() {
+            let degrees = get_input()
+                .iter()
+                .map(|s| s.differences().unwrap().degree())
+                .collect::<Vec<_>>();
+            assert_eq!(degrees, [Ok(1), Ok(2), Ok(3)]);
+
+            let sequence = "1 2 4 8 16".parse::<Sequence>().unwrap();
+            let differences = sequence.differences().unwrap();
+            assert!(!differences.is_polynomial());
+            // Still extrapolated with the polynomial through its five values
+            assert_eq!(sequence.find_next(), Ok(31));
+        }
+
+        #[test]
+        fn value_at() {
+            let sequence = "10 13 16 21 30 45".parse::<Sequence>().unwrap();
+            assert_eq!(sequence.value_at(6), Ok(68));
+            assert_eq!(sequence.value_at(7), Ok(101));
+            // 10 + 3x + 2 C(x, 3)
+            let x = 10i128.pow(12);
+            assert_eq!(
+                sequence.value_at(x),
+                Ok(10 + 3 * x + x * (x - 1) * (x - 2) / 3)
+            );
+            assert_eq!(sequence.value_at(10i128.pow(20)), Err(Overflow));
+        }
+    }
+
+    mod part_two {
+        use crate::{test::get_input, Sequence};
+
+        #[test]
+        fn find_previous() {
+            let previous_sum = crate::sum(get_input().iter().map(Sequence::find_previous));
+            assert_eq!(previous_sum, Ok(2));
+        }
+
+        #[test]
+        fn value_before() {
+            let sequence = "10 13 16 21 30 45".parse::<Sequence>().unwrap();
+            assert_eq!(sequence.value_at(-1), Ok(5));
+            assert_eq!(sequence.value_at(-2), Ok(-4));
+        }
+    }
+}
@@ -1,109 +1,30 @@
-use itertools::Itertools;
-use std::{fs, str::FromStr};
-
-fn main() {
-    let sequences = fs::read_to_string("./input.txt")
-        .unwrap()
-        .lines()
-        .map(|line| line.parse().unwrap())
-        .collect::<Vec<Sequence>>();
-    let extrapolated_values = sequences.iter().map(|s| s.find_next()).sum::<i64>();
-    println!("Part one, the sum of the extrapolated are {extrapolated_values}");
-
-    let extrapolated_values = sequences.iter().map(|s| s.find_previous()).sum::<i64>();
-    println!("Part two, the sum of the extrapolated are {extrapolated_values}");
-}
-
-#[derive(Debug, PartialEq)]
-struct Sequence(Vec<i64>);
-
-impl Sequence {
-    fn sequenciate(&self) -> Vec<Vec<i64>> {
-        let mut sequences = vec![self.0.clone()];
-
-        while !sequences.last().unwrap().iter().all(|v| *v == 0) {
-            let next_sequence = sequences
-                .last()
-                .unwrap()
-                .iter()
-                .tuple_windows::<(_, _)>()
-                .map(|(left, right)| right - left)
-                .collect_vec();
-            sequences.push(next_sequence)
+use common::Solution;
+use day_09::Day09;
+use std::{error::Error, fs, process};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let input = fs::read_to_string("./input.txt").unwrap();
+    let input = match Day09::parse(&input) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("{}", err.render("./input.txt", &input));
+            process::exit(1);
         }
-
-        sequences.reverse();
-        sequences
-    }
-
-    fn find_next(&self) -> i64 {
-        self.sequenciate()
-            .iter()
-            .fold(0, |acc, sequence| acc + sequence.last().unwrap())
-    }
-
-    fn find_previous(&self) -> i64 {
-        self.sequenciate()
-            .iter()
-            .fold(0, |acc, sequence| sequence.first().unwrap() - acc)
-    }
-}
-
-impl FromStr for Sequence {
-    type Err = ();
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let sequence = s
-            .split_whitespace()
-            .map(|word| word.parse().unwrap())
-            .collect();
-        Ok(Self(sequence))
-    }
-}
-
-#[cfg(test)]
-mod test {
-    use crate::Sequence;
-    use std::fs;
-
-    fn get_input() -> Vec<Sequence> {
-        fs::read_to_string("./example.txt")
-            .unwrap()
-            .lines()
-            .map(|line| line.parse().unwrap())
-            .collect()
-    }
-
-    mod part_one {
-        use super::get_input;
-        use crate::Sequence;
-
-        #[test]
-        fn parse() {
-            let sequences = get_input();
-            assert_eq!(
-                sequences,
-                vec![
-                    Sequence(vec![0, 3, 6, 9, 12, 15]),
-                    Sequence(vec![1, 3, 6, 10, 15, 21]),
-                    Sequence(vec![10, 13, 16, 21, 30, 45]),
-                ]
-            )
-        }
-
-        #[test]
-        fn find_next() {
-            let next_sum = get_input().iter().map(|s| s.find_next()).sum::<i64>();
-            assert_eq!(next_sum, 114);
-        }
-    }
-
-    mod part_two {
-        use crate::test::get_input;
-
-        #[test]
-        fn find_previous() {
-            let previous_sum = get_input().iter().map(|s| s.find_previous()).sum::<i64>();
-            assert_eq!(previous_sum, 2);
+    };
+    for (line, sequence) in input.iter().enumerate() {
+        if let Ok(differences) = sequence.differences() {
+            if let Err(err) = differences.degree() {
+                eprintln!("Line {}: {err}\n{differences}", line + 1);
+            }
         }
     }
+    println!(
+        "Part one, the sum of the extrapolated are {}",
+        Day09::part_one(&input)?
+    );
+    println!(
+        "Part two, the sum of the extrapolated are {}",
+        Day09::part_two(&input)?
+    );
+    Ok(())
 }
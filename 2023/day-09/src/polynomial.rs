@@ -0,0 +1,148 @@
+//! Polynomials in Newton's forward difference form
+//!
+//! A polynomial is kept as the first value of each row of the difference
+//! pyramid, `p(x) = Δ⁰ + Δ¹ C(x, 1) + Δ² C(x, 2) + ...`, where `C(x, k)` is
+//! the binomial coefficient. Those coefficients and the binomials of any
+//! integer are integers, so values are computed exactly.
+
+use crate::table::DifferenceTable;
+use std::fmt;
+
+/// A value did not fit in an `i128`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Overflow;
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value does not fit in 128 bits")
+    }
+}
+
+impl std::error::Error for Overflow {}
+
+/// Polynomial with integer values on the integers, taking `values[i]` at `i`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polynomial {
+    /// `Δᵏ p(0)` for every order `k` up to the degree
+    differences: Vec<i128>,
+}
+
+impl Polynomial {
+    /// Lowest degree polynomial through `values`, taken at `0, 1, 2...`
+    ///
+    /// Sequences that are not known to be polynomials get the polynomial of
+    /// degree `values.len() - 1` through them.
+    pub fn interpolate(values: &[i64]) -> Result<Self, Overflow> {
+        let table = DifferenceTable::new(values)?;
+        let differences = table
+            .rows()
+            .iter()
+            .filter(|row| row.iter().any(|&v| v != 0))
+            .map(|row| row[0])
+            .collect();
+        Ok(Self { differences })
+    }
+
+    /// Degree of the polynomial, `None` for the zero polynomial
+    pub fn degree(&self) -> Option<usize> {
+        self.differences.len().checked_sub(1)
+    }
+
+    /// `Δᵏ p(0)` for every order `k` up to the degree
+    pub fn differences(&self) -> &[i128] {
+        &self.differences
+    }
+
+    /// Value at `x`, which may be far outside the interpolated values or
+    /// negative
+    pub fn evaluate(&self, x: i128) -> Result<i128, Overflow> {
+        let mut value = 0i128;
+        let mut binomial = 1i128;
+        for (k, &difference) in self.differences.iter().enumerate() {
+            if k > 0 {
+                binomial = next_binomial(binomial, x, k as i128)?;
+            }
+            let term = difference.checked_mul(binomial).ok_or(Overflow)?;
+            value = value.checked_add(term).ok_or(Overflow)?;
+        }
+        Ok(value)
+    }
+}
+
+/// `C(x, k)` from `C(x, k - 1)`, which is `C(x, k - 1) * (x - k + 1) / k`
+///
+/// The division is exact, so once the common factor of `C(x, k - 1)` and `k`
+/// is taken out, what remains of `k` divides `x - k + 1`. Dividing first
+/// leaves the result as the only product, which overflows only if the result
+/// does.
+fn next_binomial(previous: i128, x: i128, k: i128) -> Result<i128, Overflow> {
+    let g = gcd(previous, k);
+    let factor = x.checked_sub(k - 1).ok_or(Overflow)?;
+    (previous / g).checked_mul(factor / (k / g)).ok_or(Overflow)
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    let (mut a, mut b) = (a.unsigned_abs(), b.unsigned_abs());
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    // At most `k` in `next_binomial`, so this fits
+    a as i128
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Overflow, Polynomial};
+
+    #[test]
+    fn interpolate() {
+        let p = Polynomial::interpolate(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(p.differences(), [10, 3, 0, 2]);
+        assert_eq!(p.degree(), Some(3));
+
+        let zero = Polynomial::interpolate(&[0, 0, 0]).unwrap();
+        assert_eq!(zero.degree(), None);
+        assert_eq!(zero.evaluate(-1000), Ok(0));
+    }
+
+    #[test]
+    fn evaluate() {
+        let values = [1, 3, 6, 10, 15, 21];
+        let p = Polynomial::interpolate(&values).unwrap();
+        for (x, &value) in values.iter().enumerate() {
+            assert_eq!(p.evaluate(x as i128), Ok(value.into()));
+        }
+        assert_eq!(p.evaluate(6), Ok(28));
+        assert_eq!(p.evaluate(-1), Ok(0));
+        assert_eq!(p.evaluate(-2), Ok(0));
+        assert_eq!(p.evaluate(-3), Ok(1));
+    }
+
+    #[test]
+    fn evaluate_far() {
+        // The triangular numbers, (x + 1)(x + 2) / 2
+        let p = Polynomial::interpolate(&[1, 3, 6, 10]).unwrap();
+        let x = 1_000_000_000_000i128;
+        assert_eq!(p.evaluate(x), Ok((x + 1) * (x + 2) / 2));
+        assert_eq!(p.evaluate(-x), Ok((-x + 1) * (-x + 2) / 2));
+
+        // x³, with negative values on the other side
+        let p = Polynomial::interpolate(&[0, 1, 8, 27, 64]).unwrap();
+        let x = 5_000_000_000_000i128;
+        assert_eq!(p.evaluate(x), Ok(x * x * x));
+        assert_eq!(p.evaluate(-x), Ok(-x * x * x));
+    }
+
+    #[test]
+    fn overflow() {
+        let p = Polynomial::interpolate(&[0, 1, 8, 27, 64]).unwrap();
+        assert!(p.evaluate(5 * 10i128.pow(12)).is_ok());
+        assert_eq!(p.evaluate(10i128.pow(13)), Err(Overflow));
+        assert_eq!(p.evaluate(i128::MIN), Err(Overflow));
+
+        let p = Polynomial::interpolate(&[i64::MIN, i64::MAX, i64::MIN]).unwrap();
+        let wide = (1i128 << 64) - 1;
+        assert_eq!(p.differences(), [i64::MIN.into(), wide, -2 * wide]);
+        assert_eq!(p.evaluate(3), Ok(i128::from(i64::MIN) - 3 * wide));
+    }
+}
@@ -0,0 +1,167 @@
+//! Difference tables, the pyramid of differences built down from a sequence
+
+use crate::polynomial::Overflow;
+use std::fmt;
+
+/// The differences of a sequence never reach a row of zeros, so it is not
+/// known to be a polynomial
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NotPolynomial {
+    /// Values in the sequence
+    pub len: usize,
+}
+
+impl fmt::Display for NotPolynomial {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "the differences of {} values never reach a row of zeros",
+            self.len
+        )
+    }
+}
+
+impl std::error::Error for NotPolynomial {}
+
+/// Rows of differences of a sequence, the sequence itself first, down to the
+/// first row of zeros or to a single value if there is none
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DifferenceTable {
+    rows: Vec<Vec<i128>>,
+}
+
+impl DifferenceTable {
+    pub fn new(values: &[i64]) -> Result<Self, Overflow> {
+        let mut rows = vec![values.iter().map(|&v| i128::from(v)).collect::<Vec<_>>()];
+        while let Some(row) = rows
+            .last()
+            .filter(|row| row.len() > 1 && row.iter().any(|&v| v != 0))
+        {
+            let differences = row
+                .windows(2)
+                .map(|pair| pair[1].checked_sub(pair[0]).ok_or(Overflow))
+                .collect::<Result<_, _>>()?;
+            rows.push(differences);
+        }
+        Ok(Self { rows })
+    }
+
+    pub fn rows(&self) -> &[Vec<i128>] {
+        &self.rows
+    }
+
+    /// Whether the table ends on a row of zeros
+    pub fn is_polynomial(&self) -> bool {
+        let last = &self.rows[self.rows.len() - 1];
+        !last.is_empty() && last.iter().all(|&v| v == 0)
+    }
+
+    /// Degree of the polynomial giving the sequence, the order of its last
+    /// row that is not zero, or `0` for a sequence of zeros
+    pub fn degree(&self) -> Result<usize, NotPolynomial> {
+        match self.is_polynomial() {
+            true => Ok(self.rows.len().saturating_sub(2)),
+            false => Err(NotPolynomial {
+                len: self.rows[0].len(),
+            }),
+        }
+    }
+}
+
+/// Draws the pyramid with each difference centered below the two values it
+/// is taken from
+///
+/// ```text
+/// 10  13  16  21  30  45
+///    3   3   5   9  15
+///      0   2   4   6
+///        2   2   2
+///          0   0
+/// ```
+impl fmt::Display for DifferenceTable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let longest = self
+            .rows
+            .iter()
+            .flatten()
+            .map(|v| v.to_string().len())
+            .max()
+            .unwrap_or(0);
+        // At least two spaces between numbers, and an even width so the
+        // rows below can shift by half a column
+        let width = (longest + 2).next_multiple_of(2);
+
+        let lines = self
+            .rows
+            .iter()
+            .enumerate()
+            .map(|(order, row)| {
+                let indent = " ".repeat(order * width / 2);
+                row.iter()
+                    .fold(indent, |line, v| line + &format!("{v:>width$}"))
+            })
+            .collect::<Vec<_>>();
+        // Shifted left as a whole, so the widest number starts the table
+        let margin = lines
+            .iter()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| line.len() - line.trim_start().len())
+            .min()
+            .unwrap_or(0);
+        for line in lines {
+            writeln!(f, "{}", line.get(margin..).unwrap_or_default())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{DifferenceTable, NotPolynomial};
+
+    #[test]
+    fn degree() {
+        let table = DifferenceTable::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(table.rows().len(), 5);
+        assert_eq!(table.rows()[3], [2, 2, 2]);
+        assert_eq!(table.degree(), Ok(3));
+
+        assert_eq!(DifferenceTable::new(&[0, 3, 6, 9]).unwrap().degree(), Ok(1));
+        assert_eq!(DifferenceTable::new(&[7, 7]).unwrap().degree(), Ok(0));
+        assert_eq!(DifferenceTable::new(&[0]).unwrap().degree(), Ok(0));
+    }
+
+    #[test]
+    fn not_polynomial() {
+        // Powers of two are their own differences
+        let table = DifferenceTable::new(&[1, 2, 4, 8, 16]).unwrap();
+        assert_eq!(table.rows().len(), 5);
+        assert_eq!(table.rows()[4], [1]);
+        assert!(!table.is_polynomial());
+        assert_eq!(table.degree(), Err(NotPolynomial { len: 5 }));
+
+        assert!(!DifferenceTable::new(&[5]).unwrap().is_polynomial());
+        assert!(!DifferenceTable::new(&[]).unwrap().is_polynomial());
+    }
+
+    #[test]
+    fn display() {
+        let table = DifferenceTable::new(&[10, 13, 16, 21, 30, 45]).unwrap();
+        assert_eq!(
+            table.to_string(),
+            "10  13  16  21  30  45\n   3   3   5   9  15\n     0   2   4   6\n       2   2   2\n         0   0\n"
+        );
+
+        let table = DifferenceTable::new(&[-100, 0, 100]).unwrap();
+        assert_eq!(
+            table.to_string(),
+            "-100     0   100\n    100   100\n         0\n"
+        );
+
+        let table = DifferenceTable::new(&[1, 1000, 1]).unwrap();
+        assert_eq!(
+            table.to_string(),
+            "1    1000       1\n  999    -999\n    -1998\n"
+        );
+    }
+}
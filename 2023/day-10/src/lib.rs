@@ -0,0 +1,503 @@
+use common::{ParseError, Solution};
+use core::fmt;
+use matrix::{format::Conventional, Element, Matrix, Size};
+use std::{
+    fmt::Display,
+    fs,
+    ops::{Index, IndexMut},
+    str::FromStr,
+};
+use strum::EnumString;
+
+pub mod render;
+pub mod shoelace;
+
+use render::Render;
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Sketch;
+    type Error = NoLoop;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Sketch>()
+    }
+
+    fn part_one(sketch: &Self::Input) -> Result<impl Display, Self::Error> {
+        sketch.get_steps_to_farthest_point()
+    }
+
+    fn part_two(sketch: &Self::Input) -> Result<impl Display, Self::Error> {
+        sketch.get_area_enclosed_by_main_loop()
+    }
+}
+
+/// No closed loop of pipes goes through the starting tile
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoLoop {
+    /// Row and column of `S`, from zero
+    pub start: (usize, usize),
+}
+
+impl fmt::Display for NoLoop {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (y, x) = self.start;
+        write!(
+            f,
+            "no closed loop goes through the start at line {}, column {}",
+            y + 1,
+            x + 1
+        )
+    }
+}
+
+impl std::error::Error for NoLoop {}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Side {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Side {
+    fn get_pos(&self, (y, x): (usize, usize)) -> Option<(usize, usize)> {
+        match self {
+            Side::Up => y.checked_sub(1).map(|y| (y, x)),
+            Side::Right => Some((y, x + 1)),
+            Side::Down => Some((y + 1, x)),
+            Side::Left => x.checked_sub(1).map(|x| (y, x)),
+        }
+    }
+
+    fn opposite(&self) -> Side {
+        match self {
+            Side::Up => Side::Down,
+            Side::Right => Side::Left,
+            Side::Down => Side::Up,
+            Side::Left => Side::Right,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, EnumString, Clone, Copy, strum::Display)]
+enum Pipe {
+    #[strum(serialize = ".")]
+    Ground,
+    #[strum(serialize = "S")]
+    Start,
+    #[strum(serialize = "|", to_string = "│")]
+    Vertical,
+    #[strum(serialize = "-", to_string = "─")]
+    Horizontal,
+    #[strum(serialize = "L", to_string = "└")]
+    NorthEast,
+    #[strum(serialize = "J", to_string = "┘")]
+    NorthWest,
+    #[strum(serialize = "7", to_string = "┐")]
+    SouthWest,
+    #[strum(serialize = "F", to_string = "┌")]
+    SouthEast,
+}
+
+impl Pipe {
+    /// Pipes that `S` may stand for
+    const SHAPES: [Pipe; 6] = [
+        Pipe::Vertical,
+        Pipe::Horizontal,
+        Pipe::NorthEast,
+        Pipe::NorthWest,
+        Pipe::SouthWest,
+        Pipe::SouthEast,
+    ];
+
+    fn get_sides(&self) -> Option<Vec<Side>> {
+        match self {
+            Pipe::Ground => None,
+            Pipe::Start => panic!("Can\'t get sides from S Pipe"),
+            Pipe::Vertical => Some(vec![Side::Up, Side::Down]),
+            Pipe::Horizontal => Some(vec![Side::Left, Side::Right]),
+            Pipe::NorthEast => Some(vec![Side::Up, Side::Right]),
+            Pipe::NorthWest => Some(vec![Side::Up, Side::Left]),
+            Pipe::SouthWest => Some(vec![Side::Down, Side::Left]),
+            Pipe::SouthEast => Some(vec![Side::Down, Side::Right]),
+        }
+    }
+
+    fn connects_from(&self, side: &Side) -> bool {
+        match self {
+            Pipe::Vertical => matches!(side, Side::Up | Side::Down),
+            Pipe::Horizontal => matches!(side, Side::Left | Side::Right),
+            Pipe::NorthEast => matches!(side, Side::Down | Side::Left),
+            Pipe::NorthWest => matches!(side, Side::Down | Side::Right),
+            Pipe::SouthWest => matches!(side, Side::Up | Side::Right),
+            Pipe::SouthEast => matches!(side, Side::Up | Side::Left),
+            _ => false,
+        }
+    }
+
+    fn is_inversion(&self) -> bool {
+        matches!(&self, Pipe::Vertical | Pipe::NorthWest | Pipe::NorthEast)
+    }
+}
+
+impl Element for Pipe {
+    fn zero() -> Self {
+        Self::Ground
+    }
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+    matrix: Conventional<Pipe>,
+    start: Option<(usize, usize)>,
+}
+
+impl Sketch {
+    pub fn from(filename: &str) -> Self {
+        let file = fs::read_to_string(filename).unwrap();
+        file.parse().unwrap()
+    }
+
+    pub fn get_steps_to_farthest_point(&self) -> Result<usize, NoLoop> {
+        Ok(self.get_main_loop_path()?.len() / 2)
+    }
+
+    fn get(&self, (y, x): (usize, usize)) -> Option<Pipe> {
+        (y < self.matrix.rows() && x < self.matrix.columns()).then(|| *self.matrix.index((y, x)))
+    }
+
+    /// Follows the pipes leaving `S` as if it were `start_pipe`, returning
+    /// the tiles walked if they lead back into `S` from its other side
+    fn trace_loop(&self, start_pipe: Pipe) -> Option<Vec<(usize, usize)>> {
+        let start = self.start?;
+        let [first, last] = start_pipe.get_sides()?.try_into().ok()?;
+
+        let mut path = vec![start];
+        let mut side = first;
+        let mut pos = side.get_pos(start)?;
+        while pos != start {
+            // Só segue pipes que conectam de volta, ignorando becos sem saída
+            let pipe = self.get(pos).filter(|pipe| pipe.connects_from(&side))?;
+            path.push(pos);
+            let from = side.opposite();
+            side = pipe.get_sides()?.into_iter().find(|side| *side != from)?;
+            pos = side.get_pos(pos)?;
+        }
+
+        (side.opposite() == last).then_some(path)
+    }
+
+    /// Shape of `S` closing the main loop and the tiles of the loop, in the
+    /// order they are walked starting at `S`
+    ///
+    /// Every shape is tried in turn, the first one leading back into `S`
+    /// wins.
+    fn trace_main_loop(&self) -> Result<(Pipe, Vec<(usize, usize)>), NoLoop> {
+        let start = self.start.expect("sketches are parsed with a start");
+        Pipe::SHAPES
+            .into_iter()
+            .find_map(|pipe| self.trace_loop(pipe).map(|path| (pipe, path)))
+            .ok_or(NoLoop { start })
+    }
+
+    /// Tiles of the main loop in the order they are walked, starting at `S`
+    pub fn get_main_loop_path(&self) -> Result<Vec<(usize, usize)>, NoLoop> {
+        self.trace_main_loop().map(|(_, path)| path)
+    }
+
+    /// Sketch with only the pipes of the main loop, `S` replaced by the pipe
+    /// it stands for
+    pub fn get_main_loop(&self) -> Result<Sketch, NoLoop> {
+        let (start_pipe, path) = self.trace_main_loop()?;
+        let mut main_loop_matrix = Conventional::zero((self.matrix.rows, self.matrix.columns));
+        for pos in path {
+            *main_loop_matrix.index_mut(pos) = *self.matrix.index(pos);
+        }
+        let start_pos = self.start.expect("sketches are parsed with a start");
+        *main_loop_matrix.index_mut(start_pos) = start_pipe;
+
+        Ok(Sketch {
+            matrix: main_loop_matrix,
+            start: self.start,
+        })
+    }
+
+    /// The main loop with the tiles inside and outside of it marked
+    pub fn render(&self) -> Result<Render, NoLoop> {
+        Render::new(self)
+    }
+
+    /// Tiles enclosed by the main loop, from the area of the polygon it
+    /// draws and the tiles on it
+    pub fn get_area_enclosed_by_main_loop(&self) -> Result<usize, NoLoop> {
+        let path = self.get_main_loop_path()?;
+        Ok(shoelace::interior_points(
+            shoelace::double_area(&path),
+            path.len(),
+        ))
+    }
+
+    pub fn get_tiles_enclosed_by_main_loop(&self) -> usize {
+        let mut tiles_enclosed_by_main_loop = 0;
+
+        for y in 0..self.matrix.rows() {
+            for x in 0..self.matrix.columns() {
+                if matches!(self.matrix.index((y, x)), Pipe::Ground) {
+                    let inversions = (0..x)
+                        .map(|column| self.matrix.index((y, column)).is_inversion())
+                        .filter(|v| *v)
+                        .count();
+
+                    if inversions % 2 == 1 {
+                        tiles_enclosed_by_main_loop += 1;
+                    }
+                }
+            }
+        }
+
+        tiles_enclosed_by_main_loop
+    }
+}
+
+impl FromStr for Sketch {
+    type Err = ParseError;
+
+    fn from_str(file: &str) -> Result<Self, Self::Err> {
+        let first_line = file
+            .lines()
+            .next()
+            .ok_or_else(|| ParseError::new(0, 0..1, "a line of pipes"))?;
+        let x = first_line.chars().count();
+        let y = file.lines().count();
+        let mut matrix = Conventional::zero((y, x));
+
+        let mut start = None;
+        for (y, line) in file.lines().enumerate() {
+            if line.chars().count() != x {
+                return Err(ParseError::at(file, line, format!("{x} tiles")));
+            }
+            for (x, (i, word)) in line.char_indices().enumerate() {
+                let pipe = word.to_string().parse().map_err(|_| {
+                    let tile = &line[i..i + word.len_utf8()];
+                    ParseError::at(
+                        file,
+                        tile,
+                        "a pipe: `|`, `-`, `L`, `J`, `7`, `F`, `.` or `S`",
+                    )
+                })?;
+                if matches!(pipe, Pipe::Start) {
+                    start = Some((y, x));
+                }
+                *matrix.index_mut((y, x)) = pipe;
+            }
+        }
+
+        if start.is_none() {
+            return Err(ParseError::after(
+                file,
+                file.trim_end(),
+                "a starting tile `S`",
+            ));
+        }
+
+        Ok(Self { matrix, start })
+    }
+}
+
+impl fmt::Display for Sketch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.matrix.rows() {
+            for x in 0..self.matrix.columns() {
+                write!(f, "{}", *self.matrix.index((y, x)))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{NoLoop, Pipe, Sketch};
+    use common::ParseError;
+    use matrix::matrix;
+    use std::fs;
+
+    #[test]
+    fn parse() {
+        let sketch = Sketch::from("./examples/simple.txt");
+        let expected = matrix![
+            Pipe::Ground, Pipe::Ground,    Pipe::Ground,     Pipe::Ground,    Pipe::Ground;
+            Pipe::Ground, Pipe::Start,     Pipe::Horizontal, Pipe::SouthWest, Pipe::Ground;
+            Pipe::Ground, Pipe::Vertical,  Pipe::Ground,     Pipe::Vertical,  Pipe::Ground;
+            Pipe::Ground, Pipe::NorthEast, Pipe::Horizontal, Pipe::NorthWest, Pipe::Ground;
+            Pipe::Ground, Pipe::Ground,    Pipe::Ground,     Pipe::Ground,    Pipe::Ground;
+        ];
+        assert_eq!(&*sketch.matrix, &*expected);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = "..F7.\n.FJ|7\nSJXL7".parse::<Sketch>().unwrap_err();
+        assert_eq!(
+            err,
+            ParseError::new(2, 2..3, "a pipe: `|`, `-`, `L`, `J`, `7`, `F`, `.` or `S`")
+        );
+
+        let err = "..F7.\n.FJ|\nSJLL7".parse::<Sketch>().unwrap_err();
+        assert_eq!(err, ParseError::new(1, 0..4, "5 tiles"));
+
+        let err = "..F7.\n.FJ|7\n".parse::<Sketch>().unwrap_err();
+        assert_eq!(err, ParseError::new(1, 5..6, "a starting tile `S`"));
+    }
+
+    #[test]
+    fn steps_to_farthest_point_simple() {
+        let sketch = Sketch::from("./examples/simple.txt");
+        assert_eq!(sketch.get_steps_to_farthest_point(), Ok(4));
+    }
+
+    #[test]
+    fn steps_to_farthest_point_complex() {
+        let sketch = Sketch::from("./examples/complex.txt");
+        assert_eq!(sketch.get_steps_to_farthest_point(), Ok(8))
+    }
+
+    #[test]
+    fn start_with_dangling_branches() {
+        // `S` connects left, right and down, but only closes a loop as `F`
+        let sketch = "\
+.....
+-S-7.
+||.|.
+.L-J.
+.....
+"
+        .parse::<Sketch>()
+        .unwrap();
+        let main_loop = sketch.get_main_loop().unwrap();
+        assert_eq!(main_loop.get((1, 1)), Some(Pipe::SouthEast));
+        assert_eq!(main_loop.get((1, 0)), Some(Pipe::Ground));
+        assert_eq!(main_loop.get((2, 0)), Some(Pipe::Ground));
+        assert_eq!(sketch.get_steps_to_farthest_point(), Ok(4));
+        assert_eq!(sketch.get_area_enclosed_by_main_loop(), Ok(1));
+    }
+
+    #[test]
+    fn start_on_the_edge() {
+        let sketch = "S7\nLJ".parse::<Sketch>().unwrap();
+        assert_eq!(sketch.get_steps_to_farthest_point(), Ok(2));
+        assert_eq!(sketch.get_area_enclosed_by_main_loop(), Ok(0));
+    }
+
+    #[test]
+    fn no_loop() {
+        let sketch = "S-7\n|.|\nL-.".parse::<Sketch>().unwrap();
+        let err = sketch.get_main_loop().unwrap_err();
+        assert_eq!(err, NoLoop { start: (0, 0) });
+        assert_eq!(
+            err.to_string(),
+            "no closed loop goes through the start at line 1, column 1"
+        );
+        assert_eq!(sketch.get_steps_to_farthest_point(), Err(err));
+
+        let sketch = "..\n.S".parse::<Sketch>().unwrap();
+        assert_eq!(
+            sketch.get_area_enclosed_by_main_loop(),
+            Err(NoLoop { start: (1, 1) })
+        );
+    }
+
+    #[test]
+    fn main_loop_path() {
+        let sketch = Sketch::from("./examples/simple.txt");
+        assert_eq!(
+            sketch.get_main_loop_path().unwrap(),
+            [
+                (1, 1),
+                (2, 1),
+                (3, 1),
+                (3, 2),
+                (3, 3),
+                (2, 3),
+                (1, 3),
+                (1, 2)
+            ]
+        );
+    }
+
+    #[test]
+    fn area_enclosed_by_main_loop() {
+        let mut examples = fs::read_dir("./examples")
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| path.to_string_lossy().contains("inside_loop_"))
+            .collect::<Vec<_>>();
+        examples.sort();
+        assert_eq!(examples.len(), 4);
+
+        for example in examples {
+            let sketch = Sketch::from(example.to_str().unwrap());
+            assert_eq!(
+                sketch.get_area_enclosed_by_main_loop().unwrap(),
+                sketch
+                    .get_main_loop()
+                    .unwrap()
+                    .get_tiles_enclosed_by_main_loop(),
+                "{}",
+                example.display()
+            );
+        }
+    }
+
+    #[test]
+    fn tiles_enclosed_by_main_loop() {
+        let sketch = Sketch::from("./examples/inside_loop_1.txt");
+        assert_eq!(
+            sketch
+                .get_main_loop()
+                .unwrap()
+                .get_tiles_enclosed_by_main_loop(),
+            4
+        );
+    }
+
+    #[test]
+    fn tiles_enclosed_by_main_loop_2() {
+        let sketch = Sketch::from("./examples/inside_loop_2.txt");
+        assert_eq!(
+            sketch
+                .get_main_loop()
+                .unwrap()
+                .get_tiles_enclosed_by_main_loop(),
+            4
+        );
+    }
+
+    #[test]
+    fn tiles_enclosed_by_main_loop_3() {
+        let sketch = Sketch::from("./examples/inside_loop_3.txt");
+        assert_eq!(
+            sketch
+                .get_main_loop()
+                .unwrap()
+                .get_tiles_enclosed_by_main_loop(),
+            8
+        );
+    }
+
+    #[test]
+    fn tiles_enclosed_by_main_loop_4() {
+        let sketch = Sketch::from("./examples/inside_loop_4.txt");
+        assert_eq!(
+            sketch
+                .get_main_loop()
+                .unwrap()
+                .get_tiles_enclosed_by_main_loop(),
+            10
+        );
+    }
+}
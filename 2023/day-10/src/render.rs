@@ -0,0 +1,211 @@
+//! Drawings of the sketch telling apart the main loop and the tiles inside
+//! and outside of it
+
+use crate::{NoLoop, Pipe, Side, Sketch};
+use matrix::Size;
+use std::{fmt, ops::Index};
+
+const LOOP_COLOR: [u8; 3] = [240, 200, 60];
+const INSIDE_COLOR: [u8; 3] = [60, 170, 90];
+const OUTSIDE_COLOR: [u8; 3] = [30, 40, 90];
+
+/// Where a tile is with respect to the main loop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Loop,
+    Inside,
+    Outside,
+}
+
+/// The main loop and every other tile marked as `I`nside or `O`utside of it
+///
+/// Displayed as text, with ANSI colours when asked to, or exported as a PPM
+/// image.
+#[derive(Debug)]
+pub struct Render {
+    main_loop: Sketch,
+    tiles: Vec<Vec<Tile>>,
+    color: bool,
+}
+
+impl Render {
+    pub fn new(sketch: &Sketch) -> Result<Self, NoLoop> {
+        let main_loop = sketch.get_main_loop()?;
+        let tiles = (0..main_loop.matrix.rows())
+            .map(|y| {
+                // Crossing an inversion flips between outside and inside
+                let mut inside = false;
+                (0..main_loop.matrix.columns())
+                    .map(|x| match main_loop.matrix.index((y, x)) {
+                        Pipe::Ground if inside => Tile::Inside,
+                        Pipe::Ground => Tile::Outside,
+                        pipe => {
+                            inside ^= pipe.is_inversion();
+                            Tile::Loop
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        Ok(Self {
+            main_loop,
+            tiles,
+            color: false,
+        })
+    }
+
+    /// Colours the text with ANSI escape codes
+    pub fn with_color(mut self, color: bool) -> Self {
+        self.color = color;
+        self
+    }
+
+    pub fn tiles(&self) -> &[Vec<Tile>] {
+        &self.tiles
+    }
+
+    pub fn count(&self, tile: Tile) -> usize {
+        self.tiles.iter().flatten().filter(|&&t| t == tile).count()
+    }
+
+    /// Binary PPM image with a square of `scale` pixels per tile, the pipes
+    /// of the loop drawn inside their squares
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scale` is below 3, too small to draw a pipe
+    pub fn to_ppm(&self, scale: usize) -> Vec<u8> {
+        assert!(scale >= 3, "tiles should be at least 3 pixels wide");
+        let rows = self.tiles.len();
+        let columns = self.tiles.first().map_or(0, Vec::len);
+        let mut image = format!("P6\n{} {}\n255\n", columns * scale, rows * scale).into_bytes();
+
+        for (y, row) in self.tiles.iter().enumerate() {
+            for py in 0..scale {
+                for (x, &tile) in row.iter().enumerate() {
+                    for px in 0..scale {
+                        let color = match tile {
+                            Tile::Inside => INSIDE_COLOR,
+                            Tile::Outside => OUTSIDE_COLOR,
+                            Tile::Loop => {
+                                let pipe = self.main_loop.matrix.index((y, x));
+                                match is_on_pipe(pipe, py * 3 / scale, px * 3 / scale) {
+                                    true => LOOP_COLOR,
+                                    false => OUTSIDE_COLOR.map(|c| c / 2),
+                                }
+                            }
+                        };
+                        image.extend(color);
+                    }
+                }
+            }
+        }
+        image
+    }
+}
+
+/// Whether the cell `(y, x)` of a 3 by 3 grid over a tile is on its pipe
+fn is_on_pipe(pipe: &Pipe, y: usize, x: usize) -> bool {
+    let sides = pipe.get_sides().unwrap_or_default();
+    match (y, x) {
+        (1, 1) => true,
+        (0, 1) => sides.contains(&Side::Up),
+        (1, 2) => sides.contains(&Side::Right),
+        (2, 1) => sides.contains(&Side::Down),
+        (1, 0) => sides.contains(&Side::Left),
+        _ => false,
+    }
+}
+
+impl fmt::Display for Render {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.tiles.iter().enumerate() {
+            for (x, tile) in row.iter().enumerate() {
+                let (text, code) = match tile {
+                    Tile::Loop => (self.main_loop.matrix.index((y, x)).to_string(), "33"),
+                    Tile::Inside => ("I".to_string(), "1;32"),
+                    Tile::Outside => ("O".to_string(), "2;34"),
+                };
+                match self.color {
+                    true => write!(f, "\x1b[{code}m{text}\x1b[0m")?,
+                    false => write!(f, "{text}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Render, Tile};
+    use crate::Sketch;
+
+    #[test]
+    fn display() {
+        let sketch = Sketch::from("./examples/inside_loop_1.txt");
+        let render = Render::new(&sketch).unwrap();
+        assert_eq!(
+            render.to_string(),
+            "\
+OOOOOOOOOOO
+O┌───────┐O
+O│┌─────┐│O
+O││OOOOO││O
+O││OOOOO││O
+O│└─┐O┌─┘│O
+O│II│O│II│O
+O└──┘O└──┘O
+OOOOOOOOOOO
+"
+        );
+    }
+
+    #[test]
+    fn color() {
+        let sketch = Sketch::from("./examples/simple.txt");
+        let text = Render::new(&sketch).unwrap().with_color(true).to_string();
+        let first = text.lines().nth(1).unwrap();
+        assert!(first.starts_with("\x1b[2;34mO\x1b[0m\x1b[33m┌\x1b[0m"));
+    }
+
+    #[test]
+    fn matches_ray_casting() {
+        for example in 1..=4 {
+            let sketch = Sketch::from(&format!("./examples/inside_loop_{example}.txt"));
+            let render = Render::new(&sketch).unwrap();
+            assert_eq!(
+                render.count(Tile::Inside),
+                sketch
+                    .get_main_loop()
+                    .unwrap()
+                    .get_tiles_enclosed_by_main_loop()
+            );
+            assert_eq!(
+                render.count(Tile::Loop),
+                sketch.get_main_loop_path().unwrap().len()
+            );
+        }
+    }
+
+    #[test]
+    fn ppm() {
+        let sketch = Sketch::from("./examples/simple.txt");
+        let image = Render::new(&sketch).unwrap().to_ppm(3);
+        let header = b"P6\n15 15\n255\n";
+        assert_eq!(&image[..header.len()], header);
+        assert_eq!(image.len(), header.len() + 15 * 15 * 3);
+
+        // Center of the top left corner of the loop, at tile (1, 1)
+        let pixel = |y: usize, x: usize| {
+            let at = header.len() + (y * 15 + x) * 3;
+            [image[at], image[at + 1], image[at + 2]]
+        };
+        assert_eq!(pixel(4, 4), super::LOOP_COLOR);
+        assert_eq!(pixel(4, 5), super::LOOP_COLOR);
+        assert_eq!(pixel(3, 4), super::OUTSIDE_COLOR.map(|c| c / 2));
+        assert_eq!(pixel(7, 7), super::INSIDE_COLOR);
+        assert_eq!(pixel(0, 0), super::OUTSIDE_COLOR);
+    }
+}
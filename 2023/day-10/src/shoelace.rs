@@ -0,0 +1,77 @@
+//! Area of a polygon on the grid from its vertices
+//!
+//! The shoelace formula gives the area of a simple polygon from its vertices
+//! in order, and Pick's theorem relates that area to the lattice points in
+//! and on it, `A = I + B / 2 - 1`. For the tiles of a loop, `B` is the length
+//! of the loop and `I` the tiles enclosed by it.
+
+/// Twice the area of the polygon with `vertices` in order, clockwise or not
+pub fn double_area(vertices: &[(usize, usize)]) -> usize {
+    let next = vertices.iter().cycle().skip(1);
+    let sum = vertices
+        .iter()
+        .zip(next)
+        .map(|(&(y1, x1), &(y2, x2))| (x1 * y2) as i64 - (x2 * y1) as i64)
+        .sum::<i64>();
+    sum.unsigned_abs() as usize
+}
+
+/// Lattice points strictly inside a polygon with `boundary` lattice points on
+/// its edges, by Pick's theorem
+pub fn interior_points(double_area: usize, boundary: usize) -> usize {
+    (double_area + 2 - boundary) / 2
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{double_area, interior_points};
+
+    #[test]
+    fn square() {
+        let corners = [(0, 0), (0, 3), (3, 3), (3, 0)];
+        assert_eq!(double_area(&corners), 18);
+
+        // Every point of the border, walked the other way around
+        let border = [
+            (0, 0),
+            (1, 0),
+            (2, 0),
+            (3, 0),
+            (3, 1),
+            (3, 2),
+            (3, 3),
+            (2, 3),
+            (1, 3),
+            (0, 3),
+            (0, 2),
+            (0, 1),
+        ];
+        assert_eq!(double_area(&border), 18);
+        assert_eq!(interior_points(double_area(&border), border.len()), 4);
+    }
+
+    #[test]
+    fn concave() {
+        // A square missing its top right quarter
+        let border = [
+            (0, 0),
+            (0, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (2, 3),
+            (2, 4),
+            (3, 4),
+            (4, 4),
+            (4, 3),
+            (4, 2),
+            (4, 1),
+            (4, 0),
+            (3, 0),
+            (2, 0),
+            (1, 0),
+        ];
+        assert_eq!(double_area(&border), 24);
+        assert_eq!(interior_points(24, border.len()), 5);
+    }
+}
@@ -0,0 +1,276 @@
+//! Queries about specific galaxies of an expanded observation
+//!
+//! Galaxies are numbered from 1 in reading order, row by row and left to
+//! right within a row, as in the puzzle.
+
+use crate::position::Coordinates;
+use std::collections::BinaryHeap;
+
+/// Galaxies indexed for distance queries
+///
+/// Distances between two galaxies and the farthest pair take constant time,
+/// the nearest galaxies to one of them are found in a k-d tree.
+#[derive(Debug, Clone)]
+pub struct GalaxyIndex {
+    /// Galaxies in reading order, galaxy `n` at `n - 1`
+    galaxies: Vec<Coordinates>,
+    /// Positions in `galaxies` laid out as a k-d tree, the root of each range
+    /// being its middle, the median of the range along rows at even depths
+    /// and along columns at odd depths
+    tree: Vec<usize>,
+    /// Positions in `galaxies` of the farthest pair
+    farthest: Option<(usize, usize)>,
+}
+
+impl GalaxyIndex {
+    pub fn new(mut galaxies: Vec<Coordinates>) -> Self {
+        galaxies.sort_unstable();
+        let mut tree = (0..galaxies.len()).collect::<Vec<_>>();
+        build(&galaxies, &mut tree, 0);
+        let farthest = farthest_pair(&galaxies);
+        Self {
+            galaxies,
+            tree,
+            farthest,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.galaxies.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.galaxies.is_empty()
+    }
+
+    /// Coordinates of galaxy `number`, counting from 1
+    pub fn get(&self, number: usize) -> Option<Coordinates> {
+        number
+            .checked_sub(1)
+            .and_then(|i| self.galaxies.get(i))
+            .copied()
+    }
+
+    /// Distance between galaxies `a` and `b`
+    pub fn distance(&self, a: usize, b: usize) -> Option<usize> {
+        Some(self.get(a)?.distance_to(&self.get(b)?))
+    }
+
+    /// The `k` galaxies nearest to galaxy `number`, as their numbers and
+    /// distances, from the nearest, ties going to the lower number
+    pub fn nearest(&self, number: usize, k: usize) -> Option<Vec<(usize, usize)>> {
+        let target = self.get(number)?;
+        let mut search = Search {
+            galaxies: &self.galaxies,
+            target,
+            exclude: number - 1,
+            k,
+            found: BinaryHeap::with_capacity(k + 1),
+        };
+        if k > 0 {
+            search.visit(&self.tree, 0);
+        }
+        let nearest = search
+            .found
+            .into_sorted_vec()
+            .into_iter()
+            .map(|(distance, i)| (i + 1, distance))
+            .collect();
+        Some(nearest)
+    }
+
+    /// The two galaxies farthest apart, as their numbers and distance
+    pub fn farthest_pair(&self) -> Option<(usize, usize, usize)> {
+        let (a, b) = self.farthest?;
+        let distance = self.galaxies[a].distance_to(&self.galaxies[b]);
+        Some((a + 1, b + 1, distance))
+    }
+}
+
+fn axis(coordinates: &Coordinates, depth: usize) -> usize {
+    let (y, x) = coordinates.position();
+    if depth.is_multiple_of(2) {
+        y
+    } else {
+        x
+    }
+}
+
+/// Lays out `tree` so its middle is the median of the range along the axis
+/// of `depth`, and each half the same way one level down
+fn build(galaxies: &[Coordinates], tree: &mut [usize], depth: usize) {
+    if tree.len() <= 1 {
+        return;
+    }
+    let middle = tree.len() / 2;
+    tree.select_nth_unstable_by_key(middle, |&i| (axis(&galaxies[i], depth), i));
+    let (left, right) = tree.split_at_mut(middle);
+    build(galaxies, left, depth + 1);
+    build(galaxies, &mut right[1..], depth + 1);
+}
+
+/// Farthest pair by Manhattan distance, which is the largest spread of
+/// either `y + x` or `y - x` over the galaxies
+fn farthest_pair(galaxies: &[Coordinates]) -> Option<(usize, usize)> {
+    let diagonals: [fn((usize, usize)) -> i64; 2] =
+        [|(y, x)| y as i64 + x as i64, |(y, x)| y as i64 - x as i64];
+    diagonals
+        .into_iter()
+        .filter_map(|diagonal| {
+            let value = |&(i, galaxy): &(usize, &Coordinates)| (diagonal(galaxy.position()), i);
+            let min = galaxies.iter().enumerate().min_by_key(value)?.0;
+            let max = galaxies.iter().enumerate().max_by_key(value)?.0;
+            let spread = diagonal(galaxies[max].position()) - diagonal(galaxies[min].position());
+            Some((spread, (min.min(max), min.max(max))))
+        })
+        .max_by_key(|&(spread, (a, b))| (spread, std::cmp::Reverse((a, b))))
+        .map(|(_, pair)| pair)
+}
+
+/// Branch and bound search of the `k` galaxies nearest to `target`
+struct Search<'a> {
+    galaxies: &'a [Coordinates],
+    target: Coordinates,
+    /// Position of the target itself, which is not its own neighbour
+    exclude: usize,
+    k: usize,
+    /// Best candidates as `(distance, position)`, the worst on top
+    found: BinaryHeap<(usize, usize)>,
+}
+
+impl Search<'_> {
+    fn visit(&mut self, tree: &[usize], depth: usize) {
+        if tree.is_empty() {
+            return;
+        }
+        let middle = tree.len() / 2;
+        let i = tree[middle];
+        let galaxy = &self.galaxies[i];
+        if i != self.exclude {
+            self.found.push((self.target.distance_to(galaxy), i));
+            if self.found.len() > self.k {
+                self.found.pop();
+            }
+        }
+
+        let (left, right) = (&tree[..middle], &tree[middle + 1..]);
+        let (target, split) = (axis(&self.target, depth), axis(galaxy, depth));
+        let (near, far) = if target < split {
+            (left, right)
+        } else {
+            (right, left)
+        };
+        self.visit(near, depth + 1);
+        // Galaxies across the split are at least this far along the axis,
+        // ties may still win on their number
+        let gap = target.abs_diff(split);
+        if self.found.len() < self.k || self.found.peek().is_some_and(|&(worst, _)| gap <= worst) {
+            self.visit(far, depth + 1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::GalaxyIndex;
+    use crate::{
+        observatory::{Observation, Raw},
+        position::Coordinates,
+    };
+    use std::fs;
+
+    fn example() -> GalaxyIndex {
+        fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand(2)
+            .index()
+    }
+
+    /// Galaxies spread over a large sky by a linear congruential generator
+    fn scattered(n: usize) -> GalaxyIndex {
+        let mut seed = 12345u64;
+        let mut next = || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize % 1000
+        };
+        let mut galaxies = (0..n)
+            .map(|_| Coordinates::new((next(), next())))
+            .collect::<Vec<_>>();
+        galaxies.sort_unstable();
+        galaxies.dedup();
+        GalaxyIndex::new(galaxies)
+    }
+
+    fn nearest_by_scanning(index: &GalaxyIndex, number: usize, k: usize) -> Vec<(usize, usize)> {
+        let mut all = (1..=index.len())
+            .filter(|&other| other != number)
+            .map(|other| (index.distance(number, other).unwrap(), other))
+            .collect::<Vec<_>>();
+        all.sort_unstable();
+        all.into_iter()
+            .take(k)
+            .map(|(distance, other)| (other, distance))
+            .collect()
+    }
+
+    #[test]
+    fn puzzle_numbering() {
+        let index = example();
+        assert_eq!(index.len(), 9);
+        assert_eq!(index.get(1), Some(Coordinates::new((0, 4))));
+        assert_eq!(index.distance(5, 9), Some(9));
+        assert_eq!(index.distance(1, 7), Some(15));
+        assert_eq!(index.distance(3, 6), Some(17));
+        assert_eq!(index.distance(8, 9), Some(5));
+        assert_eq!(index.distance(0, 1), None);
+        assert_eq!(index.distance(1, 10), None);
+    }
+
+    #[test]
+    fn nearest() {
+        let index = example();
+        assert_eq!(index.nearest(8, 1), Some(vec![(9, 5)]));
+        assert_eq!(index.nearest(8, 0), Some(vec![]));
+        assert_eq!(index.nearest(8, 20).unwrap().len(), 8);
+        assert_eq!(index.nearest(10, 1), None);
+
+        for number in 1..=index.len() {
+            for k in 0..=index.len() {
+                let nearest = index.nearest(number, k).unwrap();
+                assert_eq!(nearest, nearest_by_scanning(&index, number, k));
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_scattered() {
+        let index = scattered(500);
+        for number in (1..=index.len()).step_by(7) {
+            for k in [1, 2, 5, 20] {
+                let nearest = index.nearest(number, k).unwrap();
+                assert_eq!(nearest, nearest_by_scanning(&index, number, k));
+            }
+        }
+    }
+
+    #[test]
+    fn farthest_pair() {
+        let index = example();
+        assert_eq!(index.farthest_pair(), Some((2, 8, 19)));
+
+        let index = scattered(300);
+        let (a, b, distance) = index.farthest_pair().unwrap();
+        assert_eq!(index.distance(a, b), Some(distance));
+        let longest = (1..=index.len())
+            .flat_map(|a| (a + 1..=index.len()).map(move |b| (a, b)))
+            .map(|(a, b)| index.distance(a, b).unwrap())
+            .max();
+        assert_eq!(longest, Some(distance));
+
+        assert_eq!(GalaxyIndex::new(vec![]).farthest_pair(), None);
+    }
+}
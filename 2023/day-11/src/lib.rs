@@ -0,0 +1,119 @@
+use common::{
+    metric::{Manhattan, Metric},
+    ParseError, Solution,
+};
+use metric::Expansion;
+use observatory::{Observation, Raw};
+use position::Coordinates;
+use std::{convert::Infallible, fmt::Display};
+
+pub mod index;
+pub mod metric;
+pub mod observatory;
+pub mod position;
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Observation<Raw>;
+    type Error = Infallible;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Observation<Raw>>()
+    }
+
+    fn part_one(observation: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(sum_of_distances(observation, 2))
+    }
+
+    fn part_two(observation: &Self::Input) -> Result<impl Display, Self::Error> {
+        Ok(sum_of_distances(observation, 1_000_000))
+    }
+}
+
+/// Sum of the distances between every pair of galaxies once each empty row
+/// and column is `factor` times as wide, without expanding the image
+pub fn sum_of_distances(observation: &Observation<Raw>, factor: usize) -> usize {
+    let metric = Expansion::new(observation, factor, Manhattan);
+    calculate_min_distance_sum(observation.to_galaxies(), metric)
+}
+
+/// Sum of the distances between every pair of `galaxies` under `metric`
+pub fn calculate_min_distance_sum(galaxies: Vec<Coordinates>, metric: impl Metric) -> usize {
+    let positions = galaxies
+        .iter()
+        .map(Coordinates::position)
+        .collect::<Vec<_>>();
+    metric.pair_distance_sum(&positions)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::fs;
+
+    #[test]
+    fn part1() {
+        let galaxies = fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand(2)
+            .to_galaxies();
+        let min_distance_sum = calculate_min_distance_sum(galaxies, Manhattan);
+        assert_eq!(min_distance_sum, 374);
+    }
+
+    #[test]
+    fn part2_10() {
+        let galaxies = fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand(10)
+            .to_galaxies();
+        let min_distance_sum = calculate_min_distance_sum(galaxies, Manhattan);
+        assert_eq!(min_distance_sum, 1030);
+    }
+
+    #[test]
+    fn part2_100() {
+        let galaxies = fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand(100)
+            .to_galaxies();
+        let min_distance_sum = calculate_min_distance_sum(galaxies, Manhattan);
+        assert_eq!(min_distance_sum, 8410);
+    }
+
+    #[test]
+    fn expansion_metric() {
+        let observation = fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation");
+        for (factor, sum) in [(2, 374), (10, 1030), (100, 8410)] {
+            let metric = Expansion::new(&observation, factor, Manhattan);
+            let min_distance_sum = calculate_min_distance_sum(observation.to_galaxies(), metric);
+            assert_eq!(min_distance_sum, sum);
+        }
+    }
+
+    #[test]
+    fn sum_of_distances_without_expanding() {
+        let observation = fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation");
+        assert_eq!(sum_of_distances(&observation, 2), 374);
+        assert_eq!(sum_of_distances(&observation, 10), 1030);
+        assert_eq!(sum_of_distances(&observation, 100), 8410);
+        assert_eq!(sum_of_distances(&observation, 1_000_000), 82000210);
+        assert_eq!(
+            Day11::part_two(&observation).unwrap().to_string(),
+            "82000210"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+//! Distances on the image of an observation, before it expands
+
+use crate::observatory::{expanded_positions, Observation, Raw};
+use common::metric::{Manhattan, Metric};
+
+/// Another metric measured on the image as taken, each empty row or column
+/// crossed costing `factor` instead of 1
+///
+/// Points beyond the image are past every empty row and column of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Expansion<M = Manhattan> {
+    /// Expanded position of each row, and where the expanded rows end
+    rows: (Vec<usize>, usize),
+    /// Expanded position of each column, and where the expanded columns end
+    columns: (Vec<usize>, usize),
+    metric: M,
+}
+
+impl<M: Metric> Expansion<M> {
+    /// # Panics
+    ///
+    /// Panics if `factor` < 1
+    pub fn new(observation: &Observation<Raw>, factor: usize, metric: M) -> Self {
+        assert!(factor > 0);
+        let (rows, columns) = observation.galaxy_counts();
+        Self {
+            rows: expanded_positions(rows, factor),
+            columns: expanded_positions(columns, factor),
+            metric,
+        }
+    }
+
+    /// Where a position of the image as taken ends up once it expands
+    fn expanded(&self, (y, x): (usize, usize)) -> (usize, usize) {
+        let expanded = |(positions, end): &(Vec<usize>, usize), i: usize| match positions.get(i) {
+            Some(&position) => position,
+            None => end + (i - positions.len()),
+        };
+        (expanded(&self.rows, y), expanded(&self.columns, x))
+    }
+}
+
+impl<M: Metric> Metric for Expansion<M> {
+    fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        self.metric.distance(self.expanded(a), self.expanded(b))
+    }
+
+    fn pair_distance_sum(&self, points: &[(usize, usize)]) -> usize {
+        let expanded = points
+            .iter()
+            .map(|&point| self.expanded(point))
+            .collect::<Vec<_>>();
+        self.metric.pair_distance_sum(&expanded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Expansion;
+    use crate::observatory::{Observation, Raw};
+    use common::metric::{Chebyshev, Manhattan, Metric};
+    use std::fs;
+
+    fn example() -> Observation<Raw> {
+        fs::read_to_string("./examples/simple.txt")
+            .expect("read input")
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+    }
+
+    #[test]
+    fn expansion() {
+        // Empty rows 3 and 7, empty columns 2, 5 and 8
+        let observation = example();
+        let once = Expansion::new(&observation, 1, Manhattan);
+        assert_eq!(once.distance((5, 1), (0, 5)), 9);
+
+        // Galaxies 5 and 9 of the puzzle cross one empty row and one column
+        let twice = Expansion::new(&observation, 2, Manhattan);
+        assert_eq!(twice.distance((5, 1), (9, 4)), 9);
+
+        // The same as measuring the expanded galaxies, which keep their order
+        let mut raw = observation.to_galaxies();
+        let mut expanded = observation.expand(2).to_galaxies();
+        raw.sort_unstable();
+        expanded.sort_unstable();
+        for (i, j) in (0..raw.len()).flat_map(|i| (0..raw.len()).map(move |j| (i, j))) {
+            assert_eq!(
+                twice.distance(raw[i].position(), raw[j].position()),
+                expanded[i].distance_to(&expanded[j]),
+            );
+        }
+
+        let far = Expansion::new(&observation, 100, Chebyshev);
+        assert_eq!(far.distance((2, 0), (6, 9)), 9 + 99 * 3);
+        assert_eq!(far.distance((2, 0), (6, 1)), 4 + 99);
+        // Past the image every empty row and column is behind
+        assert_eq!(far.distance((0, 0), (20, 0)), 20 + 99 * 2);
+    }
+}
@@ -1,13 +1,13 @@
+use common::ParseError;
 use core::fmt;
 use matrix::format::Compressed;
 use matrix::prelude::Transpose;
-use matrix::Matrix;
 use matrix::{format::Conventional, Element};
 use rayon::iter::{ParallelBridge, ParallelIterator};
 use std::str::FromStr;
 use strum::EnumString;
 
-use crate::position::Coordinates;
+use crate::{index::GalaxyIndex, position::Coordinates};
 
 #[derive(Debug, strum::Display, EnumString, PartialEq, PartialOrd, Clone, Copy)]
 pub enum Item {
@@ -23,45 +23,70 @@ impl Element for Item {
     }
 }
 
+/// Bands of empty rows or columns wider than this are drawn as a single `~`
+const ELIDE_ABOVE: usize = 20;
+
 pub trait ObservationState {}
 
+/// Image of the sky, as taken or after the cosmic expansion
+///
+/// Only the image as taken is stored, an expanded observation keeps where
+/// each of its rows and columns end up instead of the expanded image.
 #[derive(Debug)]
 pub struct Observation<State: ObservationState> {
     matrix: Compressed<Item>,
-    state: std::marker::PhantomData<State>,
+    state: State,
 }
 
+#[derive(Debug)]
 pub struct Raw {}
-pub struct Expanded {}
+
+#[derive(Debug)]
+pub struct Expanded {
+    /// Expanded position of each row of the image
+    rows: Vec<usize>,
+    /// Expanded position of each column of the image
+    columns: Vec<usize>,
+    /// Rows and columns of the expanded image
+    size: (usize, usize),
+}
 
 impl ObservationState for Raw {}
 impl ObservationState for Expanded {}
 
 impl FromStr for Observation<Raw> {
-    type Err = strum::ParseError;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let image = s
             .lines()
             .map(|line| {
-                line.chars()
-                    .map(|c| c.to_string().parse::<Item>())
+                line.char_indices()
+                    .map(|(i, c)| {
+                        c.to_string().parse::<Item>().map_err(|_| {
+                            let item = &line[i..i + c.len_utf8()];
+                            ParseError::at(s, item, "`.` or `#`")
+                        })
+                    })
                     .collect()
             })
-            .collect::<Result<Vec<Vec<_>>, strum::ParseError>>()?;
+            .collect::<Result<Vec<Vec<_>>, ParseError>>()?;
 
         let y = image.len();
         let x = image.first().map_or(0, |v| v.len());
+        if let Some((line, _)) = s.lines().zip(&image).find(|(_, row)| row.len() != x) {
+            return Err(ParseError::at(s, line, format!("{x} pixels")));
+        }
         let matrix = Conventional::from_vec((y, x), image.into_iter().flatten().collect());
 
         Ok(Self {
             matrix: Compressed::from(matrix.transpose()),
-            state: std::marker::PhantomData,
+            state: Raw {},
         })
     }
 }
 
-impl<State: ObservationState> fmt::Display for Observation<State> {
+impl fmt::Display for Observation<Raw> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         for y in 0..self.matrix.rows {
             for x in 0..self.matrix.columns {
@@ -74,50 +99,49 @@ impl<State: ObservationState> fmt::Display for Observation<State> {
 }
 
 impl Observation<Raw> {
+    /// Galaxies where they are in the image as taken
+    pub fn to_galaxies(&self) -> Vec<Coordinates> {
+        self.matrix
+            .iter()
+            .map(|(y, x, _)| Coordinates::new((y, x)))
+            .collect()
+    }
+
+    /// Galaxies in each row and in each column
+    ///
+    /// Rows and columns without galaxies are the ones that expand.
+    pub fn galaxy_counts(&self) -> (Vec<usize>, Vec<usize>) {
+        galaxy_counts(&self.matrix)
+    }
+
+    /// Expands every empty row and column `to` times its width
+    ///
     /// # Panics
     ///
-    /// Panics if to < 2
+    /// Panics if to < 1
     pub fn expand(&self, to: usize) -> Observation<Expanded> {
-        assert!(to > 1);
-        let scan_offsets = |acc: &mut usize, is_void_column| {
-            if is_void_column {
-                *acc += to - 1;
-            }
-            Some(*acc)
-        };
-
-        let rows_offset = (0..self.matrix.rows)
-            .map(|y| {
-                (0..self.matrix.columns).all(|x| match self.matrix.get((y, x)) {
-                    Item::Void => true,
-                    Item::Galaxy => false,
-                })
-            })
-            .scan(0, scan_offsets)
-            .collect::<Vec<_>>();
-        let columns_offset = (0..self.matrix.columns)
-            .map(|x| {
-                (0..self.matrix.rows).all(|y| match self.matrix.get((y, x)) {
-                    Item::Void => true,
-                    Item::Galaxy => false,
-                })
-            })
-            .scan(0, scan_offsets)
-            .collect::<Vec<_>>();
-
-        let y = self.matrix.rows + rows_offset.last().copied().unwrap_or_default();
-        let x = self.matrix.columns + columns_offset.last().copied().unwrap_or_default();
-        let mut expanded = Compressed::zero((y, x));
+        self.expand_by(to, to)
+    }
 
-        for (y, x, _) in self.matrix.iter() {
-            let y1 = y + rows_offset[y];
-            let x1 = x + columns_offset[x];
-            expanded.set((y1, x1), Item::Galaxy);
-        }
+    /// Expands the empty rows `rows` times and the empty columns `columns`
+    /// times
+    ///
+    /// # Panics
+    ///
+    /// Panics if either factor is below 1
+    pub fn expand_by(&self, rows: usize, columns: usize) -> Observation<Expanded> {
+        assert!(rows > 0 && columns > 0);
+        let (row_counts, column_counts) = self.galaxy_counts();
+        let (rows, height) = expanded_positions(row_counts, rows);
+        let (columns, width) = expanded_positions(column_counts, columns);
 
         Observation {
-            matrix: expanded,
-            state: std::marker::PhantomData,
+            matrix: self.matrix.clone(),
+            state: Expanded {
+                rows,
+                columns,
+                size: (height, width),
+            },
         }
     }
 }
@@ -127,9 +151,117 @@ impl Observation<Expanded> {
         self.matrix
             .iter()
             .par_bridge()
-            .map(|(y, x, _)| Coordinates::new((y, x)))
+            .map(|(y, x, _)| Coordinates::new((self.state.rows[y], self.state.columns[x])))
             .collect::<Vec<_>>()
     }
+
+    /// Galaxies indexed for queries about specific ones
+    pub fn index(&self) -> GalaxyIndex {
+        GalaxyIndex::new(self.to_galaxies())
+    }
+
+    /// Rows and columns of the expanded image
+    pub fn size(&self) -> (usize, usize) {
+        self.state.size
+    }
+}
+
+/// Where each row or column of an axis starts once the ones without galaxies
+/// are `factor` times as wide, and where the axis ends, from the galaxies in
+/// each of them
+pub(crate) fn expanded_positions(counts: Vec<usize>, factor: usize) -> (Vec<usize>, usize) {
+    // Each row or column starts where the previous one ended
+    let mut end = 0;
+    let positions = counts
+        .into_iter()
+        .map(|count| {
+            let position = end;
+            end += if count == 0 { factor } else { 1 };
+            position
+        })
+        .collect();
+    (positions, end)
+}
+
+fn galaxy_counts(matrix: &Compressed<Item>) -> (Vec<usize>, Vec<usize>) {
+    let mut rows = vec![0; matrix.rows];
+    let mut columns = vec![0; matrix.columns];
+    for (y, x, _) in matrix.iter() {
+        rows[y] += 1;
+        columns[x] += 1;
+    }
+    (rows, columns)
+}
+
+/// Part of an axis of the expanded image when drawing it
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Band {
+    /// Row or column of the image as taken
+    Kept(usize),
+    /// Consecutive empty rows or columns, as wide as they are drawn
+    Empty(usize),
+    /// Empty rows or columns too wide to draw
+    Elided,
+}
+
+/// Splits an axis into the bands drawn, from the expanded `positions` of its
+/// rows or columns and where the last one ends
+fn bands(positions: &[usize], end: usize, is_empty: impl Fn(usize) -> bool) -> Vec<Band> {
+    let mut bands = Vec::new();
+    let mut i = 0;
+    while i < positions.len() {
+        if !is_empty(i) {
+            bands.push(Band::Kept(i));
+            i += 1;
+            continue;
+        }
+        let start = positions[i];
+        while i < positions.len() && is_empty(i) {
+            i += 1;
+        }
+        let width = positions.get(i).copied().unwrap_or(end) - start;
+        bands.push(match width > ELIDE_ABOVE {
+            true => Band::Elided,
+            false => Band::Empty(width),
+        });
+    }
+    bands
+}
+
+/// Draws the expanded image, bands of more than twenty empty rows or columns
+/// drawn as a single row or column of `~`
+impl fmt::Display for Observation<Expanded> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (row_counts, column_counts) = galaxy_counts(&self.matrix);
+        let (height, width) = self.state.size;
+        let rows = bands(&self.state.rows, height, |y| row_counts[y] == 0);
+        let columns = bands(&self.state.columns, width, |x| column_counts[x] == 0);
+
+        let line = |y: Option<usize>| {
+            columns
+                .iter()
+                .map(|column| match (y, column) {
+                    (_, Band::Elided) => "~".to_string(),
+                    (None, Band::Kept(_)) => Item::Void.to_string(),
+                    (_, Band::Empty(width)) => Item::Void.to_string().repeat(*width),
+                    (Some(y), Band::Kept(x)) => self.matrix.get((y, *x)).to_string(),
+                })
+                .collect::<String>()
+        };
+        for row in rows {
+            match row {
+                Band::Kept(y) => writeln!(f, "{}", line(Some(y)))?,
+                Band::Empty(height) => {
+                    let empty = line(None);
+                    for _ in 0..height {
+                        writeln!(f, "{empty}")?;
+                    }
+                }
+                Band::Elided => writeln!(f, "{}", "~".repeat(line(None).chars().count()))?,
+            }
+        }
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -146,6 +278,26 @@ mod tests {
         assert_eq!(input, observation.to_string());
     }
 
+    #[test]
+    fn parse_errors() {
+        let err = "..#\n.*.".parse::<Observation<Raw>>().err();
+        assert_eq!(err, Some(ParseError::new(1, 1..2, "`.` or `#`")));
+
+        let err = "..#\n..".parse::<Observation<Raw>>().err();
+        assert_eq!(err, Some(ParseError::new(1, 0..2, "3 pixels")));
+    }
+
+    #[test]
+    fn galaxy_counts() {
+        let input = fs::read_to_string("./examples/simple.txt").expect("read input");
+        let observation = input
+            .parse::<Observation<Raw>>()
+            .expect("parse observation");
+        let (rows, columns) = observation.galaxy_counts();
+        assert_eq!(rows, [1, 1, 1, 0, 1, 1, 1, 0, 1, 2]);
+        assert_eq!(columns, [2, 1, 0, 1, 1, 0, 1, 2, 0, 1]);
+    }
+
     #[test]
     fn expand() {
         let input = fs::read_to_string("./examples/simple.txt").expect("read input");
@@ -157,6 +309,51 @@ mod tests {
         assert_eq!(expanded, observation.to_string());
     }
 
+    #[test]
+    fn expand_by_axis() {
+        let input = fs::read_to_string("./examples/simple.txt").expect("read input");
+        let observation = input
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand_by(1, 3);
+        assert_eq!(observation.size(), (10, 16));
+        assert_eq!(
+            observation.to_string().lines().take(3).collect::<Vec<_>>(),
+            [".....#..........", "...........#....", "#..............."]
+        );
+    }
+
+    #[test]
+    fn expand_far() {
+        let input = fs::read_to_string("./examples/simple.txt").expect("read input");
+        let observation = input
+            .parse::<Observation<Raw>>()
+            .expect("parse observation")
+            .expand(1_000_000);
+        assert_eq!(observation.size(), (2_000_008, 3_000_007));
+
+        let galaxies = observation.to_galaxies();
+        assert_eq!(galaxies.len(), 9);
+        assert!(galaxies.contains(&Coordinates::new((2_000_007, 1_000_003))));
+        assert!(galaxies.contains(&Coordinates::new((1_000_003, 2_000_004))));
+
+        assert_eq!(
+            observation.to_string(),
+            "\
+..~#.~..~.
+..~..~.#~.
+#.~..~..~.
+~~~~~~~~~~
+..~..~#.~.
+.#~..~..~.
+..~..~..~#
+~~~~~~~~~~
+..~..~.#~.
+#.~.#~..~.
+"
+        );
+    }
+
     #[test]
     fn expand_10() {
         let input = fs::read_to_string("./examples/simple.txt").expect("read input");
@@ -1,7 +1,9 @@
+use common::metric::{Manhattan, Metric};
 use core::fmt;
 use std::ops::Sub;
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+/// Row and column of a point, ordered in reading order
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 pub struct Coordinates((usize, usize));
 
 impl Coordinates {
@@ -9,10 +11,17 @@ impl Coordinates {
         Self(position)
     }
 
+    pub fn position(&self) -> (usize, usize) {
+        self.0
+    }
+
+    /// Manhattan distance to `other`, the distance of the puzzle
     pub fn distance_to(&self, other: &Coordinates) -> usize {
-        let abs_position = self - other;
-        let (y, x) = abs_position.0;
-        y + x
+        self.distance_with(other, Manhattan)
+    }
+
+    pub fn distance_with(&self, other: &Coordinates, metric: impl Metric) -> usize {
+        metric.distance(self.0, other.0)
     }
 }
 
@@ -23,7 +32,7 @@ impl fmt::Display for Coordinates {
     }
 }
 
-impl<'a, 'b> Sub<&'b Coordinates> for &'a Coordinates {
+impl<'b> Sub<&'b Coordinates> for &Coordinates {
     type Output = Coordinates;
 
     fn sub(self, rhs: &'b Coordinates) -> Self::Output {
@@ -35,37 +44,10 @@ impl<'a, 'b> Sub<&'b Coordinates> for &'a Coordinates {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
-pub struct Pair(Coordinates, Coordinates);
-
-impl Pair {
-    pub fn new(a: Coordinates, b: Coordinates) -> Self {
-        Self(a, b)
-    }
-
-    pub fn into_coordinates(self) -> (Coordinates, Coordinates) {
-        (self.0, self.1)
-    }
-}
-
-impl PartialEq for Pair {
-    fn eq(&self, other: &Self) -> bool {
-        self.0 == other.0 && self.1 == other.1 || self.0 == other.1 && self.1 == other.0
-    }
-}
-
-impl PartialEq<(&Coordinates, &Coordinates)> for Pair {
-    fn eq(&self, other: &(&Coordinates, &Coordinates)) -> bool {
-        let (&a, &b) = other;
-        Self::new(a, b) == *self
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::position::Coordinates;
-
-    use super::Pair;
+    use common::metric::{Chebyshev, EuclideanSquared, Manhattan};
 
     #[test]
     fn sub1() {
@@ -103,20 +85,11 @@ mod tests {
     }
 
     #[test]
-    fn pair_normal() {
-        let a = Coordinates::new((1, 2));
-        let b = Coordinates::new((4, 7));
-        let p1 = Pair::new(a, b);
-        let p2 = Pair::new(a, b);
-        assert_eq!(p1, p2);
-    }
-
-    #[test]
-    fn pair_crossed() {
-        let a = Coordinates::new((1, 2));
-        let b = Coordinates::new((4, 7));
-        let p1 = Pair::new(a, b);
-        let p2 = Pair::new(b, a);
-        assert_eq!(p1, p2);
+    fn distance_with() {
+        let a = Coordinates::new((9, 0));
+        let b = Coordinates::new((4, 12));
+        assert_eq!(a.distance_with(&b, Manhattan), a.distance_to(&b));
+        assert_eq!(a.distance_with(&b, Chebyshev), 12);
+        assert_eq!(a.distance_with(&b, EuclideanSquared), 169);
     }
 }
@@ -0,0 +1,273 @@
+use common::{ParseError, Solution};
+use serde::{Deserialize, Serialize};
+use std::{
+    fmt, fs,
+    hint::black_box,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+/// Upper bound of samples taken of a single stage, keeps fast stages from
+/// collecting millions of timings
+const MAX_SAMPLES: usize = 10_000;
+
+/// Step of a solution that is timed on its own
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Stage {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "parse",
+            Stage::PartOne => "part 1",
+            Stage::PartTwo => "part 2",
+        };
+        f.pad(name)
+    }
+}
+
+/// Timings of a stage, in nanoseconds
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: u64,
+    pub median: u64,
+    pub mean: u64,
+    pub max: u64,
+}
+
+impl Stats {
+    /// # Panics
+    ///
+    /// Panics if there are no samples
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort_unstable();
+        let nanos = |d: &Duration| d.as_nanos() as u64;
+        let total: u64 = samples.iter().map(nanos).sum();
+        Self {
+            iterations: samples.len(),
+            min: nanos(&samples[0]),
+            median: nanos(&samples[samples.len() / 2]),
+            mean: total / samples.len() as u64,
+            max: nanos(&samples[samples.len() - 1]),
+        }
+    }
+}
+
+/// Timings of each stage, or why a part was not timed
+pub type Timings = Vec<(Stage, Result<Stats, String>)>;
+
+/// Timings of a stage of a day run against one of its inputs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Measurement {
+    pub year: u16,
+    pub day: u8,
+    /// Input file, relative to the day directory
+    pub input: String,
+    pub stage: Stage,
+    #[serde(flatten)]
+    pub stats: Stats,
+}
+
+impl Measurement {
+    fn same_benchmark(&self, other: &Measurement) -> bool {
+        (self.year, self.day, &self.input, self.stage)
+            == (other.year, other.day, &other.input, other.stage)
+    }
+}
+
+/// Results of a whole benchmark run, stored as JSON to compare later runs
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub measurements: Vec<Measurement>,
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let json = fs::read_to_string(path)
+            .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+        serde_json::from_str(&json)
+            .map_err(|err| format!("invalid baseline {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("could not create {}: {err}", parent.display()))?;
+        }
+        let json = serde_json::to_string_pretty(self).expect("baseline is serializable");
+        fs::write(path, json + "\n")
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn find(&self, measurement: &Measurement) -> Option<&Measurement> {
+        self.measurements
+            .iter()
+            .find(|m| m.same_benchmark(measurement))
+    }
+}
+
+/// How the median of a stage moved since the baseline
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Change {
+    /// Not present in the baseline
+    New,
+    /// Within the noise threshold
+    Unchanged(f64),
+    Improved(f64),
+    Regressed(f64),
+}
+
+impl Change {
+    /// Compares medians, `threshold` being the relative change ignored as
+    /// noise, e.g. `0.1` for 10%
+    pub fn between(baseline: Option<&Stats>, current: &Stats, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Change::New;
+        };
+        let ratio = current.median as f64 / baseline.median.max(1) as f64 - 1.0;
+        if ratio > threshold {
+            Change::Regressed(ratio)
+        } else if ratio < -threshold {
+            Change::Improved(ratio)
+        } else {
+            Change::Unchanged(ratio)
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Unchanged(r) => write!(f, "{:+.1}% unchanged", r * 100.0),
+            Change::Improved(r) => write!(f, "{:+.1}% improved", r * 100.0),
+            Change::Regressed(r) => write!(f, "{:+.1}% REGRESSED", r * 100.0),
+        }
+    }
+}
+
+/// Times `f` repeatedly until `budget` is spent, always taking at least one
+/// sample after a warm up call
+fn sample<T>(budget: Duration, mut f: impl FnMut() -> T) -> Stats {
+    black_box(f());
+
+    let mut samples = Vec::new();
+    let start = Instant::now();
+    while samples.is_empty() || (start.elapsed() < budget && samples.len() < MAX_SAMPLES) {
+        let before = Instant::now();
+        black_box(f());
+        samples.push(before.elapsed());
+    }
+    Stats::from_samples(samples)
+}
+
+/// Times parsing and both parts of a solution on `input`, spending about
+/// `budget` on each stage
+///
+/// A part without an answer for the input, e.g. one run against an example
+/// made for the other part, is not timed and reports its error instead.
+pub fn measure<S: Solution>(input: &str, budget: Duration) -> Result<Timings, ParseError> {
+    let parsed = S::parse(input)?;
+    let parse = sample(budget, || S::parse(black_box(input)).ok());
+    let part_one = match S::part_one(&parsed) {
+        Ok(_) => Ok(sample(budget, || S::part_one(black_box(&parsed)).ok())),
+        Err(err) => Err(err.to_string()),
+    };
+    let part_two = match S::part_two(&parsed) {
+        Ok(_) => Ok(sample(budget, || S::part_two(black_box(&parsed)).ok())),
+        Err(err) => Err(err.to_string()),
+    };
+    Ok(vec![
+        (Stage::Parse, Ok(parse)),
+        (Stage::PartOne, part_one),
+        (Stage::PartTwo, part_two),
+    ])
+}
+
+/// Formats nanoseconds with the unit that fits them best
+pub fn format_nanos(nanos: u64) -> String {
+    match nanos {
+        0..=999 => format!("{nanos} ns"),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(median: u64) -> Stats {
+        Stats {
+            iterations: 1,
+            min: median,
+            median,
+            mean: median,
+            max: median,
+        }
+    }
+
+    #[test]
+    fn stats_from_samples() {
+        let samples = [30, 10, 50, 20, 40].map(Duration::from_nanos).to_vec();
+        let expected = Stats {
+            iterations: 5,
+            min: 10,
+            median: 30,
+            mean: 30,
+            max: 50,
+        };
+        assert_eq!(Stats::from_samples(samples), expected);
+    }
+
+    #[test]
+    fn change() {
+        assert_eq!(Change::between(None, &stats(100), 0.1), Change::New);
+        assert!(matches!(
+            Change::between(Some(&stats(100)), &stats(105), 0.1),
+            Change::Unchanged(r) if (r - 0.05).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(Some(&stats(100)), &stats(150), 0.1),
+            Change::Regressed(r) if (r - 0.5).abs() < 1e-9
+        ));
+        assert!(matches!(
+            Change::between(Some(&stats(100)), &stats(50), 0.1),
+            Change::Improved(r) if (r + 0.5).abs() < 1e-9
+        ));
+    }
+
+    #[test]
+    fn baseline_json() {
+        let baseline = Baseline {
+            measurements: vec![Measurement {
+                year: 2023,
+                day: 5,
+                input: "example.txt".to_string(),
+                stage: Stage::PartTwo,
+                stats: stats(1_500),
+            }],
+        };
+        let json = serde_json::to_string(&baseline).unwrap();
+        assert_eq!(
+            json,
+            r#"{"measurements":[{"year":2023,"day":5,"input":"example.txt","stage":"part_two","iterations":1,"min":1500,"median":1500,"mean":1500,"max":1500}]}"#
+        );
+        assert_eq!(serde_json::from_str::<Baseline>(&json).unwrap(), baseline);
+    }
+
+    #[test]
+    fn format() {
+        assert_eq!(format_nanos(999), "999 ns");
+        assert_eq!(format_nanos(1_500), "1.50 µs");
+        assert_eq!(format_nanos(2_345_678), "2.35 ms");
+        assert_eq!(format_nanos(3_000_000_000), "3.00 s");
+    }
+}
@@ -0,0 +1,59 @@
+use day_07::{Card, Hand, Rules};
+use std::{cmp::Ordering, fs, path::Path};
+
+/// Parses the wild cards given on the command line, e.g. `J` or `2J`
+pub fn parse_rules(wild: &str) -> Result<Rules, String> {
+    let cards = wild
+        .chars()
+        .map(|label| match Rules::STANDARD.contains(Card::new(label)) {
+            true => Ok(Card::new(label)),
+            false => Err(format!("`{label}` is not a card")),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(Rules::wild(&cards))
+}
+
+/// Reads the rules of a game from a config file
+pub fn load_rules(path: &Path) -> Result<Rules, String> {
+    let config = fs::read_to_string(path)
+        .map_err(|err| format!("error: could not read {}: {err}", path.display()))?;
+    config
+        .parse()
+        .map_err(|err: common::ParseError| err.render(&path.display().to_string(), &config))
+}
+
+/// Ranks the hands of 2023 day 07, explaining the type of each hand and why
+/// it beats, or ties with, the hand ranked right below it
+pub fn explain(path: &Path, rules: Rules) -> Result<(), String> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| format!("error: could not read {}: {err}", path.display()))?;
+    let mut hands = day_07::parse_hands(&input, &rules)
+        .map_err(|err| err.render(&path.display().to_string(), &input))?;
+    hands.sort();
+
+    let width = hands.len().to_string().len();
+    let mut previous: Option<&Hand> = None;
+    for (rank, hand) in hands.iter().enumerate() {
+        let mut line = format!(
+            "{:>width$} {hand} {:>4} {}",
+            rank + 1,
+            hand.bid(),
+            hand.analyze()
+        );
+        if let Some(previous) = previous {
+            let explanation = hand
+                .explain(previous)
+                .map_err(|err| format!("error: cannot rank {hand} against {previous}: {err}"))?;
+            let verb = match explanation.ordering {
+                Ordering::Equal => "ties with",
+                _ => "beats",
+            };
+            line += &format!(", {verb} {previous}: {explanation}");
+        }
+        println!("{line}");
+        previous = Some(hand);
+    }
+    println!("total winnings: {}", day_07::get_total_winnings(&hands));
+
+    Ok(())
+}
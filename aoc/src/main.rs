@@ -0,0 +1,290 @@
+use bench::{Baseline, Change, Measurement};
+use clap::{ColorChoice, Parser, Subcommand};
+use std::{fs, path::PathBuf, process::ExitCode, time::Duration};
+
+mod bench;
+mod cards;
+mod maze;
+mod network;
+mod registry;
+
+#[derive(Debug, Parser)]
+#[command(
+    name = "aoc",
+    about = "Runs the Advent of Code solutions of this repository"
+)]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Runs the solution of a single day
+    Run {
+        year: u16,
+        day: u8,
+        /// Part to run, both parts are run when omitted
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Puzzle input, defaults to the `input.txt` inside the day directory
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Times parsing and both parts of every day, or of the given ones
+    Bench {
+        year: Option<u16>,
+        day: Option<u8>,
+        /// Benchmarks the examples instead of the puzzle inputs
+        #[arg(short, long)]
+        example: bool,
+        /// Time spent measuring each stage, in milliseconds
+        #[arg(short, long, default_value_t = 500)]
+        time: u64,
+        /// Stores the results as a JSON baseline
+        #[arg(short, long)]
+        save: Option<PathBuf>,
+        /// Compares the results with a baseline stored by an earlier run
+        #[arg(short, long)]
+        baseline: Option<PathBuf>,
+        /// Slowdown of the median, in percent, reported as a regression
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+    },
+    /// Ranks the Camel Cards hands of 2023 day 07, explaining each decision
+    Cards {
+        /// Cards that are wild, e.g. `J` for the jokers of part two
+        #[arg(short, long, default_value = "", value_parser = cards::parse_rules)]
+        wild: day_07::Rules,
+        /// Config of another game, with its deck, wild cards and hand size
+        #[arg(short, long, conflicts_with = "wild")]
+        rules: Option<PathBuf>,
+        /// Puzzle input, defaults to the `input.txt` of 2023 day 07
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Prints the desert network of 2023 day 08 as a Graphviz DOT graph
+    Network {
+        /// Highlights the walk from `AAA` to `ZZZ` of part one
+        #[arg(short, long)]
+        path: bool,
+        /// Puzzle input, defaults to the `input.txt` of 2023 day 08
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+    /// Draws the pipe maze of 2023 day 10, marking the tiles inside and
+    /// outside of the main loop with `I` and `O`
+    Maze {
+        /// When to colour the drawing
+        #[arg(long, default_value_t = ColorChoice::Auto)]
+        color: ColorChoice,
+        /// Also saves the drawing as a PPM image
+        #[arg(long)]
+        ppm: Option<PathBuf>,
+        /// Pixels per tile in the image
+        #[arg(long, default_value_t = 5, value_parser = clap::value_parser!(u16).range(3..))]
+        scale: u16,
+        /// Puzzle input, defaults to the `input.txt` of 2023 day 10
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let result = match cli.command {
+        Command::Run {
+            year,
+            day,
+            part,
+            input,
+        } => run(year, day, part, input),
+        Command::Bench {
+            year,
+            day,
+            example,
+            time,
+            save,
+            baseline,
+            threshold,
+        } => bench(BenchOptions {
+            year,
+            day,
+            example,
+            budget: Duration::from_millis(time),
+            save,
+            baseline,
+            threshold: threshold / 100.0,
+        }),
+        Command::Cards { wild, rules, input } => {
+            let path = input.unwrap_or_else(|| registry::find(2023, 7).unwrap().input_path());
+            match rules {
+                Some(rules) => {
+                    cards::load_rules(&rules).and_then(|rules| cards::explain(&path, rules))
+                }
+                None => cards::explain(&path, wild),
+            }
+        }
+        Command::Network { path, input } => {
+            let input = input.unwrap_or_else(|| registry::find(2023, 8).unwrap().input_path());
+            network::export(&input, path)
+        }
+        Command::Maze {
+            color,
+            ppm,
+            scale,
+            input,
+        } => {
+            let input = input.unwrap_or_else(|| registry::find(2023, 10).unwrap().input_path());
+            let ppm = ppm.as_deref().map(|image| (image, usize::from(scale)));
+            maze::draw(&input, color, ppm)
+        }
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("{}", err.trim_end());
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a day, failing with the report to show the user
+fn run(year: u16, day: u8, part: Option<u8>, input: Option<PathBuf>) -> Result<(), String> {
+    let day = registry::find(year, day)
+        .ok_or_else(|| format!("error: there is no solution for {year} day {day:02}"))?;
+    let path = input.unwrap_or_else(|| day.input_path());
+    let input = fs::read_to_string(&path)
+        .map_err(|err| format!("error: could not read {}: {err}", path.display()))?;
+
+    let parts = match part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    let answers = day
+        .solve(&input, &parts)
+        .map_err(|err| err.render(&path.display().to_string(), &input))?;
+    let mut errors = Vec::new();
+    for (part, answer) in parts.iter().zip(answers) {
+        match answer {
+            Ok(answer) => println!("{} day {:02} part {part}: {answer}", day.year, day.day),
+            Err(err) => errors.push(format!(
+                "error: {} day {:02} part {part} has no answer: {err}",
+                day.year, day.day
+            )),
+        }
+    }
+
+    match errors.is_empty() {
+        true => Ok(()),
+        false => Err(errors.join("\n")),
+    }
+}
+
+struct BenchOptions {
+    year: Option<u16>,
+    day: Option<u8>,
+    example: bool,
+    budget: Duration,
+    save: Option<PathBuf>,
+    baseline: Option<PathBuf>,
+    threshold: f64,
+}
+
+/// Benchmarks the selected days, failing when any stage regressed
+fn bench(options: BenchOptions) -> Result<(), String> {
+    let baseline = options
+        .baseline
+        .as_deref()
+        .map(Baseline::load)
+        .transpose()
+        .map_err(|err| format!("error: {err}"))?;
+    let days = registry::DAYS
+        .iter()
+        .filter(|d| options.year.is_none_or(|year| d.year == year))
+        .filter(|d| options.day.is_none_or(|day| d.day == day))
+        .collect::<Vec<_>>();
+    if days.is_empty() {
+        return Err("error: there are no solutions to benchmark".to_string());
+    }
+
+    let mut results = Baseline::default();
+    let mut regressions = 0;
+    for day in days {
+        let skipped = format!("{} day {:02} skipped:", day.year, day.day);
+        let path = if options.example {
+            match day.example_path() {
+                Some(path) => path,
+                None => {
+                    println!("{skipped} there is no example");
+                    continue;
+                }
+            }
+        } else {
+            day.input_path()
+        };
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{skipped} could not read {}: {err}", path.display());
+                continue;
+            }
+        };
+        let stages = day
+            .bench(&input, options.budget)
+            .map_err(|err| err.render(&path.display().to_string(), &input))?;
+        let name = path
+            .strip_prefix(day.directory())
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .into_owned();
+
+        for (stage, stats) in stages {
+            let stats = match stats {
+                Ok(stats) => stats,
+                Err(err) => {
+                    println!(
+                        "{} day {:02} {name:<19} {stage:<6} not timed, there is no answer: {err}",
+                        day.year, day.day,
+                    );
+                    continue;
+                }
+            };
+            let measurement = Measurement {
+                year: day.year,
+                day: day.day,
+                input: name.clone(),
+                stage,
+                stats,
+            };
+            let mut line = format!(
+                "{} day {:02} {name:<19} {stage:<6} {:>10} (min {}, max {}, {} iterations)",
+                day.year,
+                day.day,
+                bench::format_nanos(stats.median),
+                bench::format_nanos(stats.min),
+                bench::format_nanos(stats.max),
+                stats.iterations,
+            );
+            if let Some(baseline) = &baseline {
+                let previous = baseline.find(&measurement).map(|m| &m.stats);
+                let change = Change::between(previous, &stats, options.threshold);
+                if let Change::Regressed(_) = change {
+                    regressions += 1;
+                }
+                line += &format!(" {change}");
+            }
+            println!("{line}");
+            results.measurements.push(measurement);
+        }
+    }
+
+    if let Some(path) = &options.save {
+        results.save(path).map_err(|err| format!("error: {err}"))?;
+    }
+    match regressions {
+        0 => Ok(()),
+        n => Err(format!("error: {n} stages regressed")),
+    }
+}
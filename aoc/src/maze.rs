@@ -0,0 +1,41 @@
+use clap::ColorChoice;
+use common::Solution;
+use day_10::{render::Tile, Day10};
+use std::{
+    fs,
+    io::{self, IsTerminal},
+    path::Path,
+};
+
+/// Draws the pipe maze of 2023 day 10 with the tiles inside and outside of
+/// the main loop marked, optionally saving the drawing as a PPM image
+pub fn draw(path: &Path, color: ColorChoice, ppm: Option<(&Path, usize)>) -> Result<(), String> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| format!("error: could not read {}: {err}", path.display()))?;
+    let sketch =
+        Day10::parse(&input).map_err(|err| err.render(&path.display().to_string(), &input))?;
+
+    let color = match color {
+        ColorChoice::Auto => io::stdout().is_terminal(),
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+    };
+    let render = sketch
+        .render()
+        .map_err(|err| format!("error: {err}"))?
+        .with_color(color);
+    print!("{render}");
+    println!(
+        "loop: {} tiles, inside: {} tiles, outside: {} tiles",
+        render.count(Tile::Loop),
+        render.count(Tile::Inside),
+        render.count(Tile::Outside)
+    );
+
+    if let Some((image, scale)) = ppm {
+        fs::write(image, render.to_ppm(scale))
+            .map_err(|err| format!("error: could not write {}: {err}", image.display()))?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use common::Solution;
+use day_08::Day08;
+use std::{fs, path::Path};
+
+/// Prints the desert network of 2023 day 08 as a Graphviz DOT graph,
+/// highlighting the walk from `AAA` to `ZZZ` when `with_path` is set
+pub fn export(path: &Path, with_path: bool) -> Result<(), String> {
+    let input = fs::read_to_string(path)
+        .map_err(|err| format!("error: could not read {}: {err}", path.display()))?;
+    let map =
+        Day08::parse(&input).map_err(|err| err.render(&path.display().to_string(), &input))?;
+
+    let dot = match with_path {
+        true => {
+            let steps = map
+                .path_to_zzz()
+                .ok_or_else(|| "error: there is no walk from AAA to ZZZ".to_string())?;
+            map.dot().with_path(&steps)
+        }
+        false => map.dot(),
+    };
+    print!("{dot}");
+
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use crate::bench::{self, Timings};
+use common::{ParseError, Solution};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+/// Answer of a part, or why the input has none
+pub type Answer = Result<String, String>;
+
+/// Entry point of a single day, linking the solution crate to the runner
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&str, &[u8]) -> Result<Vec<Answer>, ParseError>,
+    bench: fn(&str, Duration) -> Result<Timings, ParseError>,
+}
+
+impl Day {
+    /// Directory of the day inside the repository, e.g. `2023/day-05`
+    pub fn directory(&self) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(self.year.to_string())
+            .join(format!("day-{:02}", self.day))
+    }
+
+    pub fn input_path(&self) -> PathBuf {
+        self.directory().join("input.txt")
+    }
+
+    /// Example of the puzzle statement, if the day has one
+    pub fn example_path(&self) -> Option<PathBuf> {
+        ["example.txt", "example_input.txt", "examples/simple.txt"]
+            .into_iter()
+            .map(|example| self.directory().join(example))
+            .find(|path| path.is_file())
+    }
+
+    /// Parses the input once and returns the answers of the requested parts
+    pub fn solve(&self, input: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+        (self.solve)(input, parts)
+    }
+
+    /// Times parsing and each part, spending about `budget` on each of them
+    pub fn bench(&self, input: &str, budget: Duration) -> Result<Timings, ParseError> {
+        (self.bench)(input, budget)
+    }
+}
+
+fn solve<S: Solution>(input: &str, parts: &[u8]) -> Result<Vec<Answer>, ParseError> {
+    let input = S::parse(input)?;
+    let answers = parts
+        .iter()
+        .map(|part| match part {
+            1 => S::part_one(&input).map(|answer| answer.to_string()),
+            2 => S::part_two(&input).map(|answer| answer.to_string()),
+            _ => panic!("There is no part {part}"),
+        })
+        .map(|answer| answer.map_err(|err| err.to_string()))
+        .collect();
+    Ok(answers)
+}
+
+macro_rules! day {
+    ($year:literal, $day:literal, $solution:ty) => {
+        Day {
+            year: $year,
+            day: $day,
+            solve: solve::<$solution>,
+            bench: bench::measure::<$solution>,
+        }
+    };
+}
+
+pub const DAYS: &[Day] = &[
+    day!(2022, 1, y2022_day_01::Day01),
+    day!(2022, 2, y2022_day_02::Day02),
+    day!(2022, 3, y2022_day_03::Day03),
+    day!(2022, 4, y2022_day_04::Day04),
+    day!(2022, 5, y2022_day_05::Day05),
+    day!(2022, 6, y2022_day_06::Day06),
+    day!(2022, 8, y2022_day_08::Day08),
+    day!(2022, 9, y2022_day_09::Day09),
+    day!(2022, 11, y2022_day_11::Day11),
+    day!(2022, 12, y2022_day_12::Day12),
+    day!(2022, 13, y2022_day_13::Day13),
+    day!(2022, 14, y2022_day_14::Day14),
+    day!(2023, 1, day_01::Day01),
+    day!(2023, 2, day_02::Day02),
+    day!(2023, 3, day_03::Day03),
+    day!(2023, 4, day_04::Day04),
+    day!(2023, 5, day_05::Day05),
+    day!(2023, 6, day_06::Day06),
+    day!(2023, 7, day_07::Day07),
+    day!(2023, 8, day_08::Day08),
+    day!(2023, 9, day_09::Day09),
+    day!(2023, 10, day_10::Day10),
+    day!(2023, 11, day_11::Day11),
+];
+
+pub fn find(year: u16, day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.year == year && d.day == day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::bench::Stage;
+    use std::fs;
+
+    #[test]
+    fn find_day() {
+        let day = find(2023, 5).expect("2023 day 5 registered");
+        assert_eq!((day.year, day.day), (2023, 5));
+        assert!(day.directory().ends_with("2023/day-05"));
+        assert!(find(2023, 25).is_none());
+        assert!(day.example_path().unwrap().ends_with("example.txt"));
+        assert!(find(2023, 1).unwrap().example_path().is_none());
+
+        let day = find(2022, 2).expect("2022 day 2 registered");
+        assert!(day.example_path().unwrap().ends_with("example_input.txt"));
+        assert!(find(2022, 7).is_none());
+        assert!(find(2022, 10).is_none());
+    }
+
+    #[test]
+    fn unique_days() {
+        for (i, a) in DAYS.iter().enumerate() {
+            for b in DAYS.iter().skip(i + 1) {
+                assert!((a.year, a.day) != (b.year, b.day));
+            }
+        }
+    }
+
+    #[test]
+    fn solve_examples() {
+        let examples = [
+            (2022, 2, "example_input.txt", ["15", "12"]),
+            (2022, 5, "example.txt", ["CMZ", "MCD"]),
+            (2023, 5, "example.txt", ["35", "46"]),
+            (2023, 6, "example.txt", ["288", "71503"]),
+            (2023, 7, "example.txt", ["6440", "5905"]),
+            (2023, 9, "example.txt", ["114", "2"]),
+            (2023, 11, "examples/simple.txt", ["374", "82000210"]),
+        ];
+        for (year, day, example, expected) in examples {
+            let day = find(year, day).unwrap();
+            let input = fs::read_to_string(day.directory().join(example)).unwrap();
+            let answers = day.solve(&input, &[1, 2]).unwrap();
+            assert_eq!(answers, expected.map(|answer| Ok(answer.to_string())));
+            let answers = day.solve(&input, &[2]).unwrap();
+            assert_eq!(answers, [Ok(expected[1].to_string())]);
+        }
+    }
+
+    #[test]
+    fn solve_without_answer() {
+        // The start has no pipes around it to close a loop
+        let day = find(2023, 10).unwrap();
+        let answers = day.solve("S.\n..\n", &[1, 2]).unwrap();
+        let error = "no closed loop goes through the start at line 1, column 1";
+        assert_eq!(answers, [Err(error.to_string()), Err(error.to_string())]);
+
+        let timings = day.bench("S.\n..\n", Duration::ZERO).unwrap();
+        let stages = timings
+            .into_iter()
+            .map(|(stage, stats)| (stage, stats.map(|_| ())))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            stages,
+            [
+                (Stage::Parse, Ok(())),
+                (Stage::PartOne, Err(error.to_string())),
+                (Stage::PartTwo, Err(error.to_string())),
+            ]
+        );
+
+        // Part two of 2022 day 14 was never written
+        let day = find(2022, 14).unwrap();
+        let answers = day.solve("498,4 -> 498,6\n", &[2]).unwrap();
+        assert_eq!(
+            answers,
+            [Err("this part has not been solved yet".to_string())]
+        );
+    }
+}
@@ -0,0 +1,160 @@
+use core::fmt;
+use std::ops::Range;
+
+/// Error of a malformed puzzle input, pointing at the offending region
+///
+/// Both the line and the columns are zero based and counted in characters.
+/// Parsers of a single line report errors against that line, the callers then
+/// move them to the right place of the whole input with [`ParseError::within`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    line: usize,
+    columns: Range<usize>,
+    expected: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, columns: Range<usize>, expected: impl Into<String>) -> Self {
+        Self {
+            line,
+            columns,
+            expected: expected.into(),
+        }
+    }
+
+    /// Error spanning `token`, which must be a slice of `source`
+    pub fn at(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, token);
+        let len = token.chars().count();
+        Self::new(line, column..column + len, expected)
+    }
+
+    /// Error right after `token`, used when something is missing after it
+    pub fn after(source: &str, token: &str, expected: impl Into<String>) -> Self {
+        let end = &token[token.len()..];
+        let (line, column) = position(source, end);
+        Self::new(line, column..column + 1, expected)
+    }
+
+    /// Moves an error reported against `inner` to the same place in `outer`,
+    /// `inner` being a slice of `outer`
+    pub fn within(mut self, outer: &str, inner: &str) -> Self {
+        let (line, column) = position(outer, inner);
+        if self.line == 0 {
+            self.columns = self.columns.start + column..self.columns.end + column;
+        }
+        self.line += line;
+        self
+    }
+
+    pub fn line(&self) -> usize {
+        self.line
+    }
+
+    pub fn columns(&self) -> Range<usize> {
+        self.columns.clone()
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Renders the error underlining the bad region of `source`, the input
+    /// that failed to parse, which was read from `origin`
+    pub fn render(&self, origin: &str, source: &str) -> String {
+        let line = source.lines().nth(self.line).unwrap_or_default();
+        let number = (self.line + 1).to_string();
+        let gutter = " ".repeat(number.len());
+        let padding = " ".repeat(self.columns.start);
+        let underline = "^".repeat(self.columns.len().max(1));
+
+        format!(
+            "error: expected {}\n{gutter}--> {origin}:{}:{}\n{gutter} |\n{number} | {line}\n{gutter} | {padding}{underline}\n",
+            self.expected,
+            self.line + 1,
+            self.columns.start + 1,
+        )
+    }
+}
+
+/// Line and column where `token` starts inside `source`
+///
+/// # Panics
+///
+/// Panics if `token` is not a slice of `source`
+fn position(source: &str, token: &str) -> (usize, usize) {
+    let offset = (token.as_ptr() as usize)
+        .checked_sub(source.as_ptr() as usize)
+        .filter(|offset| offset + token.len() <= source.len())
+        .expect("token should be a slice of source");
+    let before = &source[..offset];
+    let line = before.matches('\n').count();
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    (line, before[line_start..].chars().count())
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: expected {}",
+            self.line + 1,
+            self.columns.start + 1,
+            self.expected
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::ParseError;
+
+    const INPUT: &str = "seeds: 79 14\n\nseed-to-soil map:\n50 98 x2\n";
+
+    #[test]
+    fn at() {
+        let token = &INPUT[INPUT.find("x2").unwrap()..][..2];
+        let err = ParseError::at(INPUT, token, "a number");
+        assert_eq!(err, ParseError::new(3, 6..8, "a number"));
+    }
+
+    #[test]
+    fn after() {
+        let line = INPUT.lines().next().unwrap();
+        let err = ParseError::after(line, line, "more seeds");
+        assert_eq!(err, ParseError::new(0, 12..13, "more seeds"));
+    }
+
+    #[test]
+    fn within() {
+        let line = INPUT.lines().nth(3).unwrap();
+        let err = ParseError::at(line, &line[6..], "a number").within(INPUT, line);
+        assert_eq!(err, ParseError::new(3, 6..8, "a number"));
+
+        let block = &INPUT[INPUT.find("seed-to-soil").unwrap()..];
+        let err = ParseError::new(1, 6..8, "a number").within(INPUT, block);
+        assert_eq!(err, ParseError::new(3, 6..8, "a number"));
+    }
+
+    #[test]
+    fn display() {
+        let err = ParseError::new(3, 6..8, "a number");
+        assert_eq!(err.to_string(), "line 4, column 7: expected a number");
+    }
+
+    #[test]
+    fn render() {
+        let err = ParseError::new(3, 6..8, "a number");
+        assert_eq!(
+            err.render("input.txt", INPUT),
+            "error: expected a number
+ --> input.txt:4:7
+  |
+4 | 50 98 x2
+  |       ^^
+"
+        );
+    }
+}
@@ -0,0 +1,37 @@
+use std::{
+    error::Error,
+    fmt::{self, Display},
+};
+
+pub use error::ParseError;
+
+mod error;
+pub mod metric;
+
+/// A day of the Advent of Code, split in parsing and the two puzzle parts
+///
+/// Parsing happens once and both parts share the parsed input, so tooling can
+/// run and time each step on its own. An input that parses may still have no
+/// answer, which a part reports as its `Error`.
+pub trait Solution {
+    type Input;
+    type Error: Error;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    fn part_one(input: &Self::Input) -> Result<impl Display, Self::Error>;
+
+    fn part_two(input: &Self::Input) -> Result<impl Display, Self::Error>;
+}
+
+/// Error of a part the day's code doesn't answer yet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved;
+
+impl Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "this part has not been solved yet")
+    }
+}
+
+impl Error for Unsolved {}
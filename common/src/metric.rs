@@ -0,0 +1,142 @@
+//! Ways to measure the distance between two points of a grid
+//!
+//! Metrics take plain `(row, column)` positions, so any grid puzzle can use
+//! them whatever it stores in its cells.
+
+/// Distance between two positions of a grid
+pub trait Metric {
+    fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize;
+
+    /// Sum of the distances between every pair of `points`, each pair counted
+    /// once
+    ///
+    /// Measures each pair on its own, metrics with a faster way to sum them
+    /// should provide it.
+    fn pair_distance_sum(&self, points: &[(usize, usize)]) -> usize {
+        points
+            .iter()
+            .enumerate()
+            .flat_map(|(i, &a)| points[i + 1..].iter().map(move |&b| (a, b)))
+            .map(|(a, b)| self.distance(a, b))
+            .sum()
+    }
+}
+
+/// Steps up, down, left and right, the distance of the puzzle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+impl Metric for Manhattan {
+    fn distance(&self, (ay, ax): (usize, usize), (by, bx): (usize, usize)) -> usize {
+        ay.abs_diff(by) + ax.abs_diff(bx)
+    }
+
+    /// Rows and columns summed on their own, in `O(n log n)`
+    fn pair_distance_sum(&self, points: &[(usize, usize)]) -> usize {
+        let rows = points.iter().map(|&(y, _)| y).collect();
+        let columns = points.iter().map(|&(_, x)| x).collect();
+        axis_distance_sum(rows) + axis_distance_sum(columns)
+    }
+}
+
+/// Sum of the distances between every pair of `positions` along one axis
+///
+/// Once sorted, a position `p` is `p - q` away from each position `q` before
+/// it, so all those distances add up to `seen * p - sum of q`.
+fn axis_distance_sum(mut positions: Vec<usize>) -> usize {
+    positions.sort_unstable();
+    let mut sum = 0;
+    let mut positions_seen = 0;
+    for (seen, position) in positions.into_iter().enumerate() {
+        sum += seen * position - positions_seen;
+        positions_seen += position;
+    }
+    sum
+}
+
+/// Steps that may also go diagonally, like a king on a chessboard
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Chebyshev;
+
+impl Metric for Chebyshev {
+    fn distance(&self, (ay, ax): (usize, usize), (by, bx): (usize, usize)) -> usize {
+        ay.abs_diff(by).max(ax.abs_diff(bx))
+    }
+}
+
+/// Square of the straight line distance, which stays an integer and orders
+/// points the same way
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EuclideanSquared;
+
+impl Metric for EuclideanSquared {
+    fn distance(&self, (ay, ax): (usize, usize), (by, bx): (usize, usize)) -> usize {
+        let (y, x) = (ay.abs_diff(by), ax.abs_diff(bx));
+        y * y + x * x
+    }
+}
+
+impl<T: Metric + ?Sized> Metric for &T {
+    fn distance(&self, a: (usize, usize), b: (usize, usize)) -> usize {
+        (**self).distance(a, b)
+    }
+
+    fn pair_distance_sum(&self, points: &[(usize, usize)]) -> usize {
+        (**self).pair_distance_sum(points)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Chebyshev, EuclideanSquared, Manhattan, Metric};
+
+    #[test]
+    fn distance1() {
+        let (a, b) = ((5, 1), (0, 5));
+        assert_eq!(Manhattan.distance(a, b), 9);
+        assert_eq!(Chebyshev.distance(a, b), 5);
+        assert_eq!(EuclideanSquared.distance(a, b), 41);
+    }
+
+    #[test]
+    fn distance2() {
+        let (a, b) = ((9, 0), (4, 12));
+        assert_eq!(Manhattan.distance(a, b), 17);
+        assert_eq!(Chebyshev.distance(a, b), 12);
+        assert_eq!(EuclideanSquared.distance(a, b), 169);
+    }
+
+    #[test]
+    fn symmetric() {
+        let (a, b) = ((9, 0), (4, 12));
+        assert_eq!(Manhattan.distance(b, a), Manhattan.distance(a, b));
+        assert_eq!(Chebyshev.distance(b, a), Chebyshev.distance(a, b));
+        assert_eq!(
+            EuclideanSquared.distance(b, a),
+            EuclideanSquared.distance(a, b)
+        );
+        assert_eq!(Manhattan.distance(a, a), 0);
+    }
+
+    #[test]
+    fn pair_distance_sum() {
+        let points = [(5, 1), (0, 5), (9, 0), (4, 12), (5, 1)];
+        let each_pair = |metric: &dyn Metric| {
+            let mut sum = 0;
+            for i in 0..points.len() {
+                for j in i + 1..points.len() {
+                    sum += metric.distance(points[i], points[j]);
+                }
+            }
+            sum
+        };
+        assert_eq!(Manhattan.pair_distance_sum(&points), each_pair(&Manhattan));
+        assert_eq!(Chebyshev.pair_distance_sum(&points), each_pair(&Chebyshev));
+        assert_eq!(
+            EuclideanSquared.pair_distance_sum(&points),
+            each_pair(&EuclideanSquared)
+        );
+        assert_eq!(Manhattan.pair_distance_sum(&points[..1]), 0);
+        assert_eq!(Manhattan.pair_distance_sum(&[]), 0);
+    }
+}